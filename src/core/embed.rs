@@ -0,0 +1,149 @@
+//! Provides the user-facing functions for embedding values.
+//!
+//! This module contains the `embed` function, which embeds a single value,
+//! and the `embed_many` function, which embeds any number of values by
+//! splitting them into batches the model accepts and sending the batches in
+//! parallel.
+
+use futures::{StreamExt, TryStreamExt, stream};
+
+use crate::{
+    core::{
+        embedding_model::EmbeddingModel,
+        types::{
+            EmbedCallOptions, EmbedManyCallOptions, EmbedManyResponse, EmbedResponse,
+            EmbeddingModelCallOptions, EmbeddingModelResponse, EmbeddingUsage,
+        },
+    },
+    error::{Error, Result},
+};
+
+/// Embeds a single value using a specified embedding model.
+///
+/// # Arguments
+///
+/// * `model` - An embedding model that implements the `EmbeddingModel` trait.
+///
+/// * `options` - An `EmbedCallOptions` struct containing the value to embed.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to embed the value.
+pub async fn embed(model: impl EmbeddingModel, options: EmbedCallOptions) -> Result<EmbedResponse> {
+    let values = vec![options.value];
+    let response = embed_batch(&model, values).await?;
+
+    Ok(EmbedResponse {
+        embedding: response.embeddings.into_iter().next().unwrap_or_default(),
+        usage: response.usage,
+    })
+}
+
+/// Embeds many values using a specified embedding model.
+///
+/// The values are split into batches of the model's maximum number of
+/// embeddings per call, and the batches are sent in parallel, up to
+/// `max_parallel_calls` at a time. The embeddings are returned in the order
+/// of the values.
+///
+/// # Arguments
+///
+/// * `model` - An embedding model that implements the `EmbeddingModel` trait.
+///
+/// * `options` - An `EmbedManyCallOptions` struct containing the values to
+///   embed and the parallelism of the requests.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to embed any batch.
+pub async fn embed_many(
+    model: impl EmbeddingModel,
+    options: EmbedManyCallOptions,
+) -> Result<EmbedManyResponse> {
+    let batch_size = model.max_embeddings_per_call().unwrap_or(usize::MAX).max(1);
+    let batches: Vec<Vec<String>> = options
+        .values
+        .chunks(batch_size)
+        .map(<[String]>::to_vec)
+        .collect();
+
+    let max_parallel_calls = if model.supports_parallel_calls() {
+        options.max_parallel_calls.unwrap_or(batches.len())
+    } else {
+        1
+    };
+
+    let responses: Vec<EmbeddingModelResponse> = stream::iter(batches)
+        .map(|values| embed_batch(&model, values))
+        .buffered(max_parallel_calls.max(1))
+        .try_collect()
+        .await?;
+
+    let mut embeddings = Vec::with_capacity(options.values.len());
+    let mut usage: Option<EmbeddingUsage> = None;
+    for response in responses {
+        embeddings.extend(response.embeddings);
+        if let Some(batch_usage) = response.usage {
+            usage = Some(usage.unwrap_or_default() + batch_usage);
+        }
+    }
+
+    Ok(EmbedManyResponse { embeddings, usage })
+}
+
+/// Embeds a batch of values, checking that the model returned an embedding
+/// for every value.
+async fn embed_batch(
+    model: &impl EmbeddingModel,
+    values: Vec<String>,
+) -> Result<EmbeddingModelResponse> {
+    let expected = values.len();
+    let response = model.embed(EmbeddingModelCallOptions { values }).await?;
+
+    if response.embeddings.len() != expected {
+        return Err(Error::ParseError(format!(
+            "expected {expected} embeddings, but the model returned {}",
+            response.embeddings.len()
+        )));
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockEmbeddingModel;
+
+    #[tokio::test]
+    async fn test_embed_many_batches_values_in_order() {
+        let model = MockEmbeddingModel::new(Some(2));
+        let options = EmbedManyCallOptions::builder()
+            .values(
+                ["a", "bb", "ccc", "dddd", "eeeee"]
+                    .map(String::from)
+                    .to_vec(),
+            )
+            .build()
+            .unwrap();
+
+        let response = embed_many(&model, options).await.unwrap();
+
+        assert_eq!(
+            response.embeddings,
+            vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0], vec![5.0]]
+        );
+        assert_eq!(response.usage, Some(EmbeddingUsage { tokens: 3 }));
+        assert_eq!(model.calls().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_embed_returns_single_embedding() {
+        let model = MockEmbeddingModel::new(Some(2));
+        let options = EmbedCallOptions::builder().value("hello").build().unwrap();
+
+        let response = embed(&model, options).await.unwrap();
+
+        assert_eq!(response.embedding, vec![5.0]);
+    }
+}
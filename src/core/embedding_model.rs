@@ -0,0 +1,43 @@
+//! Defines the `EmbeddingModel` trait for models that turn values into
+//! vector embeddings.
+//!
+//! Embedding models are used for semantic search, clustering and similar
+//! tasks. They are separate from language models, as providers usually serve
+//! them under different model names and endpoints.
+
+use crate::core::types::{EmbeddingModelCallOptions, EmbeddingModelResponse};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// The core trait abstracting the capabilities of an embedding model.
+///
+/// Implementors embed a batch of values in a single request. Splitting large
+/// inputs into batches the provider accepts is left to `embed_many`, guided
+/// by `max_embeddings_per_call`.
+#[async_trait]
+pub trait EmbeddingModel: Send + Sync + std::fmt::Debug {
+    /// Returns the identifier of the model (e.g., "text-embedding-3-small").
+    fn model_name(&self) -> &str;
+
+    /// Returns the name of the provider (e.g., "openai").
+    fn provider_name(&self) -> &str;
+
+    /// Returns the maximum number of values the model embeds in a single
+    /// call, or `None` if there is no limit.
+    fn max_embeddings_per_call(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether several calls to the model may be made at the same
+    /// time.
+    fn supports_parallel_calls(&self) -> bool {
+        true
+    }
+
+    /// Embeds a batch of values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails or the request is invalid.
+    async fn embed(&self, options: EmbeddingModelCallOptions) -> Result<EmbeddingModelResponse>;
+}
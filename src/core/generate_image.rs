@@ -0,0 +1,97 @@
+//! Provides the user-facing function for image generation.
+//!
+//! This module contains the `generate_image` function, which generates any
+//! number of images by splitting the request into calls the model accepts
+//! and sending them in parallel.
+
+use futures::{StreamExt, TryStreamExt, stream};
+
+use crate::{
+    core::{
+        image_model::ImageModel,
+        types::{GenerateImageCallOptions, GenerateImageResponse, ImageModelResponse},
+    },
+    error::Result,
+};
+
+/// Generates images using a specified image model.
+///
+/// When more images are requested than the model generates in a single
+/// call, the request is split into several calls that are sent in parallel,
+/// up to `max_parallel_calls` at a time.
+///
+/// # Arguments
+///
+/// * `model` - An image model that implements the `ImageModel` trait.
+///
+/// * `options` - A `GenerateImageCallOptions` struct containing the prompt,
+///   the number of images and their size, quality and style.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to generate any of the
+/// images.
+pub async fn generate_image(
+    model: impl ImageModel,
+    options: GenerateImageCallOptions,
+) -> Result<GenerateImageResponse> {
+    let per_call = model.max_images_per_call().unwrap_or(u32::MAX).max(1);
+    let counts = (0..options.n)
+        .step_by(per_call as usize)
+        .map(|generated| per_call.min(options.n - generated))
+        .collect::<Vec<_>>();
+    let max_parallel_calls = options.max_parallel_calls.unwrap_or(counts.len());
+
+    let responses: Vec<ImageModelResponse> = stream::iter(counts)
+        .map(|n| model.generate(options.call_options(n)))
+        .buffered(max_parallel_calls.max(1))
+        .try_collect()
+        .await?;
+
+    Ok(GenerateImageResponse {
+        images: responses
+            .into_iter()
+            .flat_map(|response| response.images)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockImageModel;
+
+    #[tokio::test]
+    async fn test_generate_image_splits_calls() {
+        let model = MockImageModel::new(Some(2));
+        let options = GenerateImageCallOptions::builder()
+            .prompt("a lighthouse at dusk")
+            .n(5)
+            .build()
+            .unwrap();
+
+        let response = generate_image(&model, options).await.unwrap();
+
+        assert_eq!(response.images.len(), 5);
+        assert_eq!(
+            model.calls().iter().map(|call| call.n).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_image_limits_parallel_calls() {
+        let model = MockImageModel::new(Some(1));
+        let options = GenerateImageCallOptions::builder()
+            .prompt("a lighthouse at dusk")
+            .n(3)
+            .max_parallel_calls(1)
+            .build()
+            .unwrap();
+
+        let response = generate_image(&model, options).await.unwrap();
+
+        assert_eq!(response.images.len(), 3);
+        assert_eq!(model.calls().len(), 3);
+    }
+}
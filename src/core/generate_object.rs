@@ -0,0 +1,295 @@
+//! Provides the user-facing function for structured object generation.
+//!
+//! This module contains the `generate_object` function, which asks a model
+//! for a JSON value matching the schema of a Rust type and deserializes the
+//! response into that type.
+
+use schemars::{JsonSchema, Schema};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        types::{
+            GenerateObjectCallOptions, GenerateObjectResponse, LanguageModelCallOptions,
+            ResponseFormat,
+        },
+    },
+    error::{Error, Result},
+};
+
+/// Generates an object of type `T` using a specified language model.
+///
+/// A JSON Schema is derived from `T` and sent to the model as a structured
+/// output constraint when the model supports it. Otherwise the schema is
+/// added to the system prompt as an instruction. The response is then parsed
+/// and deserialized into `T`.
+///
+/// # Arguments
+///
+/// * `model` - A language model that implements the `LanguageModel` trait.
+///
+/// * `options` - A `GenerateObjectCallOptions` struct containing the system
+///   prompt, the prompt or conversation history, and schema metadata.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to generate a response,
+/// or an `Error::ParseError` containing the response if it is not valid JSON
+/// for `T`.
+pub async fn generate_object<T: DeserializeOwned + JsonSchema>(
+    model: impl LanguageModel,
+    options: GenerateObjectCallOptions,
+) -> Result<GenerateObjectResponse<T>> {
+    let schema = ObjectSchema::of::<T>();
+    let call_options = object_call_options::<T>(&model, &options, &schema);
+
+    let response = model.generate(call_options).await?;
+    let object = schema.parse(&response.text)?;
+
+    Ok(GenerateObjectResponse {
+        object,
+        finish_reason: response.finish_reason,
+        usage: response.usage,
+    })
+}
+
+/// The property that values which are not JSON objects are wrapped in.
+const WRAPPER_PROPERTY: &str = "elements";
+
+/// The JSON Schema sent to the model for a Rust type.
+///
+/// Structured outputs require the root of the schema to be an object, so the
+/// schema of any other type, e.g. `Vec<T>`, is wrapped in an object with a
+/// single `elements` property, which is unwrapped again when parsing.
+#[derive(Debug, Clone)]
+pub(crate) struct ObjectSchema {
+    schema: Value,
+    wrapped: bool,
+}
+
+impl ObjectSchema {
+    /// Derives the schema of `T`, without the `$schema` meta keyword.
+    pub(crate) fn of<T: JsonSchema>() -> Self {
+        let mut schema: Schema = schemars::schema_for!(T);
+        schema.remove("$schema");
+        let mut schema = schema.to_value();
+
+        if schema.get("type") == Some(&Value::from("object")) {
+            return Self {
+                schema,
+                wrapped: false,
+            };
+        }
+
+        // Definitions are referenced from the root, so they stay there.
+        let defs = schema
+            .as_object_mut()
+            .and_then(|schema| schema.remove("$defs"));
+        let mut wrapper = json!({
+            "type": "object",
+            "properties": { WRAPPER_PROPERTY: schema },
+            "required": [WRAPPER_PROPERTY],
+            "additionalProperties": false,
+        });
+        if let Some(defs) = defs {
+            wrapper["$defs"] = defs;
+        }
+
+        Self {
+            schema: wrapper,
+            wrapped: true,
+        }
+    }
+
+    /// Unwraps a (possibly partial) value parsed from the model response.
+    pub(crate) fn unwrap_value(&self, value: Value) -> Option<Value> {
+        if !self.wrapped {
+            return Some(value);
+        }
+        match value {
+            Value::Object(mut object) => object.remove(WRAPPER_PROPERTY),
+            _ => None,
+        }
+    }
+
+    /// Parses the model response into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::ParseError` containing the response if it is not
+    /// valid JSON for `T`.
+    pub(crate) fn parse<T: DeserializeOwned>(&self, text: &str) -> Result<T> {
+        serde_json::from_str(extract_json(text))
+            .and_then(|value| {
+                let value = self.unwrap_value(value).unwrap_or_default();
+                serde_json::from_value(value)
+            })
+            .map_err(|err| {
+                Error::ParseError(format!(
+                    "the model response does not match the schema: {err}, response: {text}"
+                ))
+            })
+    }
+}
+
+/// Builds the call options for generating an object of type `T`, using a
+/// structured output response format when the model supports it and prompt
+/// instructions otherwise.
+pub(crate) fn object_call_options<T: JsonSchema>(
+    model: &impl LanguageModel,
+    options: &GenerateObjectCallOptions,
+    schema: &ObjectSchema,
+) -> LanguageModelCallOptions {
+    let mut call_options = options.call_options();
+
+    if model.supports_structured_output() {
+        let name = options
+            .schema_name
+            .clone()
+            .unwrap_or_else(|| T::schema_name().into_owned());
+        call_options.response_format = Some(ResponseFormat::Json {
+            schema: Some(schema.schema.clone()),
+            name: Some(name),
+            description: options.schema_description.clone(),
+        });
+    } else {
+        let instructions =
+            schema_instructions(&schema.schema, options.schema_description.as_deref());
+        call_options.system = Some(match call_options.system {
+            Some(system) => format!("{system}\n\n{instructions}"),
+            None => instructions,
+        });
+    }
+
+    call_options
+}
+
+/// Returns prompt instructions asking the model to respond with JSON that
+/// matches the given schema.
+fn schema_instructions(schema: &serde_json::Value, description: Option<&str>) -> String {
+    let mut instructions = String::new();
+    if let Some(description) = description {
+        instructions.push_str(description);
+        instructions.push_str("\n\n");
+    }
+    instructions.push_str(
+        "Respond only with a JSON value that matches the following JSON Schema, \
+         without any other text or formatting:\n",
+    );
+    instructions.push_str(&schema.to_string());
+    instructions
+}
+
+/// Strips whitespace and Markdown code fences that models commonly wrap
+/// JSON responses in.
+pub(crate) fn extract_json(text: &str) -> &str {
+    let text = text.trim();
+    match text.strip_prefix("```") {
+        Some(fenced) => {
+            let fenced = fenced.strip_prefix("json").unwrap_or(fenced);
+            fenced.strip_suffix("```").unwrap_or(fenced).trim()
+        }
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockLanguageModel;
+    use crate::core::types::LanguageModelResponse;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Recipe {
+        name: String,
+        steps: Vec<String>,
+    }
+
+    fn options() -> GenerateObjectCallOptions {
+        GenerateObjectCallOptions::builder()
+            .prompt("A pancake recipe")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_with_structured_output() {
+        let model = MockLanguageModel::new([LanguageModelResponse::new(
+            r#"{"name":"Pancakes","steps":["Mix","Fry"]}"#,
+        )])
+        .with_structured_output(true);
+
+        let response = generate_object::<Recipe>(&model, options()).await.unwrap();
+
+        assert_eq!(
+            response.object,
+            Recipe {
+                name: "Pancakes".to_string(),
+                steps: vec!["Mix".to_string(), "Fry".to_string()],
+            }
+        );
+        let calls = model.calls();
+        let Some(ResponseFormat::Json { schema, name, .. }) = &calls[0].response_format else {
+            panic!("expected a JSON response format");
+        };
+        assert_eq!(name.as_deref(), Some("Recipe"));
+        assert_eq!(
+            schema.as_ref().unwrap()["required"],
+            serde_json::json!(["name", "steps"])
+        );
+        assert_eq!(calls[0].system, None);
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_falls_back_to_prompt_instructions() {
+        let model = MockLanguageModel::new([LanguageModelResponse::new(
+            "```json\n{\"name\":\"Pancakes\",\"steps\":[]}\n```",
+        )]);
+
+        let response = generate_object::<Recipe>(&model, options()).await.unwrap();
+
+        assert_eq!(response.object.name, "Pancakes");
+        let calls = model.calls();
+        assert_eq!(calls[0].response_format, None);
+        assert!(calls[0].system.as_ref().unwrap().contains("JSON Schema"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_wraps_non_object_schemas() {
+        let model = MockLanguageModel::new([LanguageModelResponse::new(
+            r#"{"elements":[{"name":"Pancakes","steps":["Mix"]},{"name":"Toast","steps":[]}]}"#,
+        )])
+        .with_structured_output(true);
+
+        let response = generate_object::<Vec<Recipe>>(&model, options())
+            .await
+            .unwrap();
+
+        assert_eq!(response.object.len(), 2);
+        assert_eq!(response.object[1].name, "Toast");
+        let calls = model.calls();
+        let Some(ResponseFormat::Json { schema, .. }) = &calls[0].response_format else {
+            panic!("expected a JSON response format");
+        };
+        let schema = schema.as_ref().unwrap();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["elements"]["type"], "array");
+        assert!(schema["$defs"]["Recipe"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_generate_object_rejects_invalid_json() {
+        let model = MockLanguageModel::new([LanguageModelResponse::new(r#"{"name":"Pancakes"}"#)])
+            .with_structured_output(true);
+
+        let result = generate_object::<Recipe>(&model, options()).await;
+
+        let Err(Error::ParseError(message)) = result else {
+            panic!("expected a parse error");
+        };
+        assert!(message.contains(r#"{"name":"Pancakes"}"#));
+    }
+}
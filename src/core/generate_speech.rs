@@ -0,0 +1,56 @@
+//! Provides the user-facing functions for speech synthesis.
+//!
+//! This module contains the `generate_speech` function, which returns the
+//! complete audio of the speech, and the `stream_speech` function, which
+//! returns the audio as it is generated so that playback can start early.
+
+use crate::{
+    core::{
+        speech_model::SpeechModel,
+        types::{GenerateSpeechCallOptions, GenerateSpeechResponse, StreamSpeechResponse},
+    },
+    error::Result,
+};
+
+/// Generates speech from text using a specified speech model.
+///
+/// # Arguments
+///
+/// * `model` - A speech model that implements the `SpeechModel` trait.
+///
+/// * `options` - A `GenerateSpeechCallOptions` struct containing the text
+///   and the voice, format and speed of the speech.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to generate the speech.
+pub async fn generate_speech(
+    model: impl SpeechModel,
+    options: GenerateSpeechCallOptions,
+) -> Result<GenerateSpeechResponse> {
+    let response = model.generate(options.call_options()).await?;
+
+    Ok(GenerateSpeechResponse {
+        audio: response.audio,
+        media_type: response.media_type,
+    })
+}
+
+/// Streams speech generated from text using a specified speech model.
+///
+/// # Arguments
+///
+/// * `model` - A speech model that implements the `SpeechModel` trait.
+///
+/// * `options` - A `GenerateSpeechCallOptions` struct containing the text
+///   and the voice, format and speed of the speech.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to start the stream.
+pub async fn stream_speech(
+    model: impl SpeechModel,
+    options: GenerateSpeechCallOptions,
+) -> Result<StreamSpeechResponse> {
+    model.stream(options.call_options()).await
+}
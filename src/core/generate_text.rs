@@ -4,10 +4,20 @@
 //! main entry point for consumers of the SDK to generate text using any
 //! model that implements the `LanguageModel` trait.
 
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use futures::future::join_all;
+
 use crate::{
     core::{
         language_model::LanguageModel,
-        types::{GenerateTextCallOptions, GenerateTextResponse, LanguageModelCallOptions},
+        tool::Tool,
+        types::{
+            GenerateTextCallOptions, GenerateTextResponse, Message, StepResult, ToolCall,
+            ToolResult,
+        },
     },
     error::Result,
 };
@@ -18,13 +28,20 @@ use crate::{
 /// set of options, invoking the `generate` method on the provided language model,
 /// and returning a standardized response.
 ///
+/// When tools are provided, the tool calls requested by the model are executed
+/// and their results are sent back to the model in a new step, until the model
+/// answers without calling a tool or `max_steps` steps have been made. The
+/// tool calls of the last step are executed as well, and their results are
+/// returned in the response without being sent to the model. Unknown tools,
+/// malformed arguments and tool failures are reported to the model as error
+/// results, so that it can recover from them.
 ///
 /// # Arguments
 ///
 /// * `model` - A language model that implements the `LanguageModel` trait.
 ///
-/// * `options` - A `GenerateTextCallOptions` struct containing the model, prompt,
-///   and other parameters for the request.
+/// * `options` - A `GenerateTextCallOptions` struct containing the system prompt,
+///   the prompt or conversation history, and other parameters for the request.
 ///
 /// # Errors
 ///
@@ -33,15 +50,260 @@ pub async fn generate_text(
     model: impl LanguageModel,
     options: GenerateTextCallOptions,
 ) -> Result<GenerateTextResponse> {
-    let response = model
-        .generate(
-            LanguageModelCallOptions::builder()
-                .prompt(options.prompt)
-                .build()?,
+    let mut call_options = options.call_options();
+    call_options.tools = options.tools.iter().map(|tool| tool.definition()).collect();
+    let mut steps = Vec::new();
+
+    for _ in 0..options.max_steps.max(1) {
+        let response = model.generate(call_options.clone()).await?;
+        let tool_results = join_all(
+            response
+                .tool_calls
+                .iter()
+                .map(|call| execute_tool(&options.tools, call)),
         )
-        .await?;
+        .await;
+
+        steps.push(StepResult {
+            text: response.text,
+            tool_calls: response.tool_calls,
+            tool_results,
+            finish_reason: response.finish_reason,
+            usage: response.usage,
+        });
 
-    let result = GenerateTextResponse::new(response.text);
+        let step = steps.last().expect("a step was just pushed");
+        if step.tool_calls.is_empty() {
+            break;
+        }
+
+        call_options
+            .messages
+            .push(Message::assistant_with_tool_calls(
+                step.text.clone(),
+                step.tool_calls.clone(),
+            ));
+        call_options
+            .messages
+            .extend(step.tool_results.iter().map(tool_message));
+    }
+
+    let usage = steps
+        .iter()
+        .filter_map(|step| step.usage)
+        .reduce(|total, usage| total + usage);
+    let last = steps.last().cloned().expect("at least one step is made");
+    let result = GenerateTextResponse {
+        text: last.text,
+        tool_calls: last.tool_calls,
+        tool_results: last.tool_results,
+        finish_reason: last.finish_reason,
+        usage,
+        steps,
+    };
 
     Ok(result)
 }
+
+/// Converts a tool result into a tool message. Errors are sent as plain text,
+/// and other results as JSON.
+fn tool_message(result: &ToolResult) -> Message {
+    match &result.result {
+        Value::String(error) if result.is_error => Message::tool_error(&result.tool_call_id, error),
+        value => Message::tool(&result.tool_call_id, value.to_string()),
+    }
+}
+
+/// Executes a tool call with the matching tool from the given set. An unknown
+/// tool, arguments that are not valid JSON or a failure of the tool is
+/// returned as an error result.
+async fn execute_tool(tools: &[Arc<dyn Tool>], call: &ToolCall) -> ToolResult {
+    let result = match tools.iter().find(|tool| tool.name() == call.name) {
+        Some(tool) => match &call.arguments {
+            Value::String(arguments) => Err(format!("Invalid JSON arguments: {arguments}")),
+            arguments => tool
+                .execute(arguments.clone())
+                .await
+                .map_err(|err| err.to_string()),
+        },
+        None => Err(format!("Unknown tool: {}", call.name)),
+    };
+
+    ToolResult {
+        tool_call_id: call.id.clone(),
+        name: call.name.clone(),
+        is_error: result.is_err(),
+        result: result.unwrap_or_else(Value::String),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockLanguageModel;
+    use crate::core::types::LanguageModelResponse;
+    use crate::error::Error;
+    use async_trait::async_trait;
+    use serde_json::json;
+
+    #[derive(Debug)]
+    struct Add;
+
+    #[async_trait]
+    impl Tool for Add {
+        fn name(&self) -> &str {
+            "add"
+        }
+
+        fn description(&self) -> &str {
+            "Adds two numbers."
+        }
+
+        fn parameters(&self) -> Value {
+            json!({"type": "object"})
+        }
+
+        async fn execute(&self, arguments: Value) -> Result<Value> {
+            match (arguments["a"].as_i64(), arguments["b"].as_i64()) {
+                (Some(a), Some(b)) => Ok(json!(a + b)),
+                _ => Err(Error::Other("a and b must be integers".to_string())),
+            }
+        }
+    }
+
+    fn tool_call_response() -> LanguageModelResponse {
+        let mut response = LanguageModelResponse::new("");
+        response.tool_calls = vec![ToolCall {
+            id: "call_1".to_string(),
+            name: "add".to_string(),
+            arguments: json!({"a": 1, "b": 2}),
+        }];
+        response
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_runs_tool_loop() {
+        let model = MockLanguageModel::new([
+            tool_call_response(),
+            LanguageModelResponse::new("The answer is 3."),
+        ]);
+        let options = GenerateTextCallOptions::builder()
+            .prompt("What is 1 + 2?")
+            .tool(Add)
+            .max_steps(5)
+            .build()
+            .unwrap();
+
+        let response = generate_text(&model, options).await.unwrap();
+
+        assert_eq!(response.text, "The answer is 3.");
+        assert_eq!(response.steps.len(), 2);
+        assert_eq!(response.steps[0].tool_results[0].result, json!(3));
+
+        let calls = model.calls();
+        assert_eq!(calls[0].tools[0].name, "add");
+        assert_eq!(
+            calls[1].messages[1..],
+            [
+                Message::assistant_with_tool_calls("", tool_call_response().tool_calls),
+                Message::tool("call_1", "3"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_stops_at_max_steps() {
+        let model = MockLanguageModel::new([tool_call_response(), tool_call_response()]);
+        let options = GenerateTextCallOptions::builder()
+            .prompt("What is 1 + 2?")
+            .tool(Add)
+            .build()
+            .unwrap();
+
+        let response = generate_text(&model, options).await.unwrap();
+
+        assert_eq!(response.steps.len(), 1);
+        assert_eq!(response.tool_results[0].result, json!(3));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_unknown_tools_to_the_model() {
+        let model = MockLanguageModel::new([
+            tool_call_response(),
+            LanguageModelResponse::new("I cannot add numbers."),
+        ]);
+        let options = GenerateTextCallOptions::builder()
+            .prompt("What is 1 + 2?")
+            .max_steps(2)
+            .build()
+            .unwrap();
+
+        let response = generate_text(&model, options).await.unwrap();
+
+        assert_eq!(response.text, "I cannot add numbers.");
+        let result = &response.steps[0].tool_results[0];
+        assert!(result.is_error);
+        assert_eq!(result.result, json!("Unknown tool: add"));
+        assert_eq!(
+            model.calls()[1].messages.last(),
+            Some(&Message::tool_error("call_1", "Unknown tool: add"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_malformed_arguments_to_the_model() {
+        let mut malformed_call = tool_call_response();
+        malformed_call.tool_calls[0].arguments = json!("{\"a\": 1, \"b\":");
+        let model = MockLanguageModel::new([
+            malformed_call,
+            tool_call_response(),
+            LanguageModelResponse::new("The answer is 3."),
+        ]);
+        let options = GenerateTextCallOptions::builder()
+            .prompt("What is 1 + 2?")
+            .tool(Add)
+            .max_steps(3)
+            .build()
+            .unwrap();
+
+        let response = generate_text(&model, options).await.unwrap();
+
+        assert_eq!(response.text, "The answer is 3.");
+        let result = &response.steps[0].tool_results[0];
+        assert!(result.is_error);
+        assert_eq!(
+            result.result,
+            json!("Invalid JSON arguments: {\"a\": 1, \"b\":")
+        );
+        assert_eq!(response.steps[1].tool_results[0].result, json!(3));
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_reports_tool_failures_to_the_model() {
+        let mut failing_call = tool_call_response();
+        failing_call.tool_calls[0].arguments = json!({"a": "one", "b": 2});
+        let model = MockLanguageModel::new([
+            failing_call,
+            LanguageModelResponse::new("The arguments were invalid."),
+        ]);
+        let options = GenerateTextCallOptions::builder()
+            .prompt("What is one + 2?")
+            .tool(Add)
+            .max_steps(2)
+            .build()
+            .unwrap();
+
+        let response = generate_text(&model, options).await.unwrap();
+
+        let result = &response.steps[0].tool_results[0];
+        assert!(result.is_error);
+        assert!(
+            result
+                .result
+                .as_str()
+                .unwrap()
+                .contains("a and b must be integers")
+        );
+        assert_eq!(response.steps.len(), 2);
+    }
+}
@@ -0,0 +1,33 @@
+//! Defines the `ImageModel` trait for models that generate images from a
+//! text prompt.
+
+use crate::core::types::{ImageModelCallOptions, ImageModelResponse};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// The core trait abstracting the capabilities of an image model.
+///
+/// Implementors generate a number of images in a single request. Splitting
+/// larger requests into calls the provider accepts is left to
+/// `generate_image`, guided by `max_images_per_call`.
+#[async_trait]
+pub trait ImageModel: Send + Sync + std::fmt::Debug {
+    /// Returns the identifier of the model (e.g., "dall-e-3").
+    fn model_name(&self) -> &str;
+
+    /// Returns the name of the provider (e.g., "openai").
+    fn provider_name(&self) -> &str;
+
+    /// Returns the maximum number of images the model generates in a single
+    /// call, or `None` if there is no limit.
+    fn max_images_per_call(&self) -> Option<u32> {
+        None
+    }
+
+    /// Generates images from a prompt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails or the request is invalid.
+    async fn generate(&self, options: ImageModelCallOptions) -> Result<ImageModelResponse>;
+}
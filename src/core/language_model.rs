@@ -5,7 +5,7 @@
 //! underlying implementation details of different AI providers, offering a
 //! unified interface for various operations like text generation or streaming.
 
-use crate::core::types::{LanguageModelCallOptions, LanguageModelResponse};
+use crate::core::types::{LanguageModelCallOptions, LanguageModelResponse, LanguageModelStream};
 use crate::error::Result;
 use async_trait::async_trait;
 
@@ -28,6 +28,15 @@ pub trait LanguageModel: Send + Sync + std::fmt::Debug {
     /// This helps differentiate between models with similar names from different services.
     fn provider_name(&self) -> &str;
 
+    /// Returns whether the model can be constrained to a JSON Schema through
+    /// `ResponseFormat::Json`.
+    ///
+    /// Models that do not support structured outputs are instructed to follow
+    /// the schema through the prompt instead.
+    fn supports_structured_output(&self) -> bool {
+        false
+    }
+
     /// Performs a single, non-streaming text generation request.
     ///
     /// This method sends a prompt to the model and returns the entire response at once.
@@ -36,4 +45,15 @@ pub trait LanguageModel: Send + Sync + std::fmt::Debug {
     ///
     /// Returns an `Error` if the API call fails or the request is invalid.
     async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse>;
+
+    /// Performs a streaming text generation request.
+    ///
+    /// This method sends a prompt to the model and returns a stream of chunks
+    /// as they are produced. Failures that occur after the stream has started
+    /// are reported as `LanguageModelStreamChunk::Error` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails before streaming begins.
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream>;
 }
@@ -0,0 +1,142 @@
+//! Provides helpers for determining the media type of files sent to models,
+//! such as audio to transcribe and images in user messages.
+//!
+//! Media types are looked up from file extensions, or detected from the
+//! leading bytes of the file content when no file name is available.
+
+use std::path::Path;
+
+/// A known media type.
+struct MediaType {
+    /// The media type, e.g. `"audio/mpeg"`.
+    name: &'static str,
+
+    /// The file extensions of the media type. The first one is canonical.
+    extensions: &'static [&'static str],
+
+    /// Whether the leading bytes of a file's content match the media type.
+    matches: fn(&[u8]) -> bool,
+}
+
+const MEDIA_TYPES: &[MediaType] = &[
+    MediaType {
+        name: "image/png",
+        extensions: &["png"],
+        matches: |bytes| bytes.starts_with(b"\x89PNG\r\n\x1a\n"),
+    },
+    MediaType {
+        name: "image/jpeg",
+        extensions: &["jpg", "jpeg"],
+        matches: |bytes| bytes.starts_with(&[0xFF, 0xD8, 0xFF]),
+    },
+    MediaType {
+        name: "image/gif",
+        extensions: &["gif"],
+        matches: |bytes| bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a"),
+    },
+    MediaType {
+        name: "image/webp",
+        extensions: &["webp"],
+        matches: |bytes| bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP"),
+    },
+    MediaType {
+        name: "application/pdf",
+        extensions: &["pdf"],
+        matches: |bytes| bytes.starts_with(b"%PDF-"),
+    },
+    MediaType {
+        name: "audio/mpeg",
+        extensions: &["mp3", "mpga", "mpeg"],
+        matches: |bytes| {
+            bytes.starts_with(b"ID3")
+                || (bytes.len() > 1 && bytes[0] == 0xFF && bytes[1] & 0xE0 == 0xE0)
+        },
+    },
+    MediaType {
+        name: "audio/wav",
+        extensions: &["wav"],
+        matches: |bytes| bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WAVE"),
+    },
+    MediaType {
+        name: "audio/flac",
+        extensions: &["flac"],
+        matches: |bytes| bytes.starts_with(b"fLaC"),
+    },
+    MediaType {
+        name: "audio/ogg",
+        extensions: &["ogg", "oga", "opus"],
+        matches: |bytes| bytes.starts_with(b"OggS"),
+    },
+    MediaType {
+        name: "audio/webm",
+        extensions: &["webm"],
+        matches: |bytes| bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]),
+    },
+    MediaType {
+        name: "audio/mp4",
+        extensions: &["m4a", "mp4"],
+        matches: |bytes| bytes.get(4..8) == Some(b"ftyp"),
+    },
+    MediaType {
+        name: "audio/aac",
+        extensions: &["aac"],
+        matches: |_| false,
+    },
+];
+
+/// Returns the media type of a file based on its extension.
+pub fn from_path(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    MEDIA_TYPES
+        .iter()
+        .find(|media_type| media_type.extensions.contains(&extension.as_str()))
+        .map(|media_type| media_type.name)
+}
+
+/// Detects the media type of a file from the leading bytes of its content.
+pub fn detect(bytes: &[u8]) -> Option<&'static str> {
+    MEDIA_TYPES
+        .iter()
+        .find(|media_type| (media_type.matches)(bytes))
+        .map(|media_type| media_type.name)
+}
+
+/// Returns the canonical file extension of a media type.
+pub fn extension(media_type: &str) -> Option<&'static str> {
+    MEDIA_TYPES
+        .iter()
+        .find(|known| known.name == media_type)
+        .map(|known| known.extensions[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(from_path(Path::new("memo.MP3")), Some("audio/mpeg"));
+        assert_eq!(from_path(Path::new("call.m4a")), Some("audio/mp4"));
+        assert_eq!(from_path(Path::new("scan.JPG")), Some("image/jpeg"));
+        assert_eq!(from_path(Path::new("notes.txt")), None);
+        assert_eq!(from_path(Path::new("recording")), None);
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect(b"RIFF\x24\x08\x00\x00WAVEfmt "), Some("audio/wav"));
+        assert_eq!(detect(b"ID3\x04\x00"), Some("audio/mpeg"));
+        assert_eq!(detect(b"\x00\x00\x00\x20ftypM4A "), Some("audio/mp4"));
+        assert_eq!(detect(b"\x89PNG\r\n\x1a\n\x00"), Some("image/png"));
+        assert_eq!(detect(b"RIFF\x24\x08\x00\x00WEBPVP8 "), Some("image/webp"));
+        assert_eq!(detect(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(detect(b"hello"), None);
+    }
+
+    #[test]
+    fn test_extension() {
+        assert_eq!(extension("audio/mpeg"), Some("mp3"));
+        assert_eq!(extension("audio/wav"), Some("wav"));
+        assert_eq!(extension("text/plain"), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! Scripted models for testing the functions of the `core` module.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+
+use crate::core::embedding_model::EmbeddingModel;
+use crate::core::image_model::ImageModel;
+use crate::core::language_model::LanguageModel;
+use crate::core::transcription_model::TranscriptionModel;
+use crate::core::types::{
+    EmbeddingModelCallOptions, EmbeddingModelResponse, EmbeddingUsage, GeneratedImage, ImageData,
+    ImageModelCallOptions, ImageModelResponse, LanguageModelCallOptions, LanguageModelResponse,
+    LanguageModelStream, LanguageModelStreamChunk, TranscriptionModelCallOptions,
+    TranscriptionModelResponse,
+};
+use crate::error::{Error, Result};
+
+/// A language model that replays scripted responses, one per call, and
+/// records the calls it receives. Streamed responses are split into chunks.
+#[derive(Debug, Default)]
+pub(crate) struct MockLanguageModel {
+    responses: Mutex<VecDeque<LanguageModelResponse>>,
+    calls: Mutex<Vec<LanguageModelCallOptions>>,
+    structured_output: bool,
+}
+
+impl MockLanguageModel {
+    /// Creates a model that responds with the given responses in order.
+    pub(crate) fn new(responses: impl IntoIterator<Item = LanguageModelResponse>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into_iter().collect()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether the model claims to support structured outputs.
+    pub(crate) fn with_structured_output(mut self, structured_output: bool) -> Self {
+        self.structured_output = structured_output;
+        self
+    }
+
+    /// Returns the calls the model received, in order.
+    pub(crate) fn calls(&self) -> Vec<LanguageModelCallOptions> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn respond(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        self.calls.lock().unwrap().push(options);
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| Error::Other("no scripted response left".to_string()))
+    }
+}
+
+#[async_trait]
+impl LanguageModel for &MockLanguageModel {
+    fn model_name(&self) -> &str {
+        "mock"
+    }
+
+    fn provider_name(&self) -> &str {
+        "mock"
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        self.structured_output
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        self.respond(options)
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let response = self.respond(options)?;
+
+        // Split the text in two deltas to exercise the accumulation of text.
+        let middle = response.text.len() / 2;
+        let middle = (middle..=response.text.len())
+            .find(|&index| response.text.is_char_boundary(index))
+            .unwrap_or_default();
+        let (head, tail) = response.text.split_at(middle);
+
+        let mut chunks: Vec<_> = [head, tail]
+            .into_iter()
+            .filter(|text| !text.is_empty())
+            .map(|text| LanguageModelStreamChunk::TextDelta(text.to_string()))
+            .collect();
+        chunks.extend(
+            response
+                .tool_calls
+                .into_iter()
+                .map(LanguageModelStreamChunk::ToolCall),
+        );
+        chunks.push(LanguageModelStreamChunk::Finish {
+            finish_reason: response.finish_reason,
+        });
+        chunks.extend(response.usage.map(LanguageModelStreamChunk::Usage));
+
+        Ok(stream::iter(chunks).boxed())
+    }
+}
+
+/// An embedding model that embeds each value as its length and records the
+/// calls it receives.
+#[derive(Debug, Default)]
+pub(crate) struct MockEmbeddingModel {
+    max_embeddings_per_call: Option<usize>,
+    calls: Mutex<Vec<EmbeddingModelCallOptions>>,
+}
+
+impl MockEmbeddingModel {
+    /// Creates a model that accepts up to `max_embeddings_per_call` values
+    /// per call.
+    pub(crate) fn new(max_embeddings_per_call: Option<usize>) -> Self {
+        Self {
+            max_embeddings_per_call,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the calls the model received, in order.
+    pub(crate) fn calls(&self) -> Vec<EmbeddingModelCallOptions> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for &MockEmbeddingModel {
+    fn model_name(&self) -> &str {
+        "mock"
+    }
+
+    fn provider_name(&self) -> &str {
+        "mock"
+    }
+
+    fn max_embeddings_per_call(&self) -> Option<usize> {
+        self.max_embeddings_per_call
+    }
+
+    async fn embed(&self, options: EmbeddingModelCallOptions) -> Result<EmbeddingModelResponse> {
+        let embeddings = options
+            .values
+            .iter()
+            .map(|value| vec![value.len() as f32])
+            .collect();
+        self.calls.lock().unwrap().push(options);
+
+        Ok(EmbeddingModelResponse {
+            embeddings,
+            usage: Some(EmbeddingUsage { tokens: 1 }),
+        })
+    }
+}
+
+/// An image model that returns one URL per requested image and records the
+/// calls it receives.
+#[derive(Debug, Default)]
+pub(crate) struct MockImageModel {
+    max_images_per_call: Option<u32>,
+    calls: Mutex<Vec<ImageModelCallOptions>>,
+}
+
+impl MockImageModel {
+    /// Creates a model that generates up to `max_images_per_call` images per
+    /// call.
+    pub(crate) fn new(max_images_per_call: Option<u32>) -> Self {
+        Self {
+            max_images_per_call,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the calls the model received, in order.
+    pub(crate) fn calls(&self) -> Vec<ImageModelCallOptions> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ImageModel for &MockImageModel {
+    fn model_name(&self) -> &str {
+        "mock"
+    }
+
+    fn provider_name(&self) -> &str {
+        "mock"
+    }
+
+    fn max_images_per_call(&self) -> Option<u32> {
+        self.max_images_per_call
+    }
+
+    async fn generate(&self, options: ImageModelCallOptions) -> Result<ImageModelResponse> {
+        let images = (0..options.n)
+            .map(|i| GeneratedImage {
+                data: ImageData::Url(format!("https://example.com/{i}.png")),
+                revised_prompt: None,
+            })
+            .collect();
+        self.calls.lock().unwrap().push(options);
+
+        Ok(ImageModelResponse { images })
+    }
+}
+
+/// A transcription model that responds with the media type of the audio as
+/// the transcript and records the calls it receives.
+#[derive(Debug, Default)]
+pub(crate) struct MockTranscriptionModel {
+    calls: Mutex<Vec<TranscriptionModelCallOptions>>,
+}
+
+impl MockTranscriptionModel {
+    /// Returns the calls the model received, in order.
+    pub(crate) fn calls(&self) -> Vec<TranscriptionModelCallOptions> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl TranscriptionModel for &MockTranscriptionModel {
+    fn model_name(&self) -> &str {
+        "mock"
+    }
+
+    fn provider_name(&self) -> &str {
+        "mock"
+    }
+
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelCallOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        let response = TranscriptionModelResponse {
+            text: options.media_type.clone(),
+            language: options.language.clone(),
+            duration: None,
+            segments: Vec::new(),
+        };
+        self.calls.lock().unwrap().push(options);
+
+        Ok(response)
+    }
+}
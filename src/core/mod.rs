@@ -2,18 +2,68 @@
 //!
 //! This module provides the essential building blocks for interacting with language models.
 //! It defines the `LanguageModel` trait, which all model providers must implement,
-//! and includes the primary `generate_text` function for initiating text generation.
+//! and includes the primary `generate_text` and `stream_text` functions for
+//! initiating text generation, as well as the `EmbeddingModel` trait and the
+//! `embed` and `embed_many` functions for embedding values. The `vector`
+//! module provides similarity search over the resulting embeddings, and the
+//! `ImageModel` trait and `generate_image` function generate images. Speech
+//! is synthesized with the `SpeechModel` trait and the `generate_speech` and
+//! `stream_speech` functions, and transcribed with the `TranscriptionModel`
+//! trait and the `transcribe` function. Providers retry failed requests
+//! according to a `RetryPolicy`.
 //!
 //! Key types like `GenerateTextCallOptions` and `GenerateTextResponse` are also
 //! re-exported for convenient access.
 
+pub mod embed;
+pub mod embedding_model;
+pub mod generate_image;
+pub mod generate_object;
+pub mod generate_speech;
 pub mod generate_text;
+pub mod image_model;
 pub mod language_model;
+pub mod media_type;
+#[cfg(test)]
+pub(crate) mod mock;
+pub mod partial_json;
 pub mod provider;
+pub mod retry;
+pub mod speech_model;
+pub mod stream_object;
+pub mod stream_text;
+pub mod tool;
+pub mod transcribe;
+pub mod transcription_model;
 pub mod types;
+pub mod vector;
 
 // Re-export key components to provide a clean public API.
+pub use embed::{embed, embed_many};
+pub use embedding_model::EmbeddingModel;
+pub use generate_image::generate_image;
+pub use generate_object::generate_object;
+pub use generate_speech::{generate_speech, stream_speech};
 pub use generate_text::generate_text;
+pub use image_model::ImageModel;
 pub use language_model::LanguageModel;
 pub use provider::Provider;
-pub use types::{GenerateTextCallOptions, GenerateTextResponse};
+pub use retry::RetryPolicy;
+pub use schemars::JsonSchema;
+pub use speech_model::SpeechModel;
+pub use stream_object::stream_object;
+pub use stream_text::stream_text;
+pub use tool::Tool;
+pub use transcribe::transcribe;
+pub use transcription_model::TranscriptionModel;
+pub use types::{
+    AudioFormat, AudioInput, ContentPart, EmbedCallOptions, EmbedManyCallOptions,
+    EmbedManyResponse, EmbedResponse, Embedding, EmbeddingUsage, FinishReason,
+    GenerateImageCallOptions, GenerateImageResponse, GenerateObjectCallOptions,
+    GenerateObjectResponse, GenerateSpeechCallOptions, GenerateSpeechResponse,
+    GenerateTextCallOptions, GenerateTextResponse, GeneratedImage, ImageData, ImageOutput,
+    ImageSource, LanguageModelStreamChunk, Message, ObjectStreamChunk, ResponseFormat, Role,
+    StepResult, StreamObjectCallOptions, StreamObjectResponse, StreamSpeechResponse,
+    StreamTextCallOptions, StreamTextResponse, ToolCall, ToolDefinition, ToolResult,
+    TranscribeCallOptions, TranscribeResponse, TranscriptionSegment, Usage,
+};
@@ -0,0 +1,363 @@
+//! A tolerant parser for incomplete JSON documents.
+//!
+//! Language models stream JSON one token at a time, so the text received so
+//! far is usually not a valid document. This module parses such prefixes into
+//! the most complete `serde_json::Value` they describe: open objects and arrays
+//! are closed, partial strings are kept as they are, and incomplete keys and
+//! literals are dropped. Numbers are kept once they are valid, so `12` may be
+//! the start of `123`, while prefixes such as `-`, `1.` or `1e` are dropped.
+//!
+//! # Examples
+//!
+//! ```
+//! use ai_sdk_rs::core::partial_json::parse_partial_json;
+//! use serde_json::json;
+//!
+//! let value = parse_partial_json(r#"{"name": "Pan", "steps": ["Mix", "#);
+//! assert_eq!(value, Some(json!({"name": "Pan", "steps": ["Mix"]})));
+//! ```
+
+use serde_json::{Map, Number, Value};
+
+/// Parses a possibly incomplete JSON document.
+///
+/// Returns `None` if no value has started yet or if the text is not a prefix
+/// of a valid JSON document.
+pub fn parse_partial_json(text: &str) -> Option<Value> {
+    if let Ok(value) = serde_json::from_str(text) {
+        return Some(value);
+    }
+
+    let tokens = tokenize(text)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    match parser.value()? {
+        Parsed::Complete(value) | Parsed::Partial(value) => Some(value),
+    }
+}
+
+/// A lexical token of a JSON document.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    BeginObject,
+    EndObject,
+    BeginArray,
+    EndArray,
+    Colon,
+    Comma,
+    /// A string and whether its closing quote has been received.
+    String(String, bool),
+    /// A number, literal or other bare word and whether it is known to be complete.
+    Word(String, bool),
+}
+
+/// Splits the text into tokens. The last token may be incomplete.
+fn tokenize(text: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '{' => Token::BeginObject,
+            '}' => Token::EndObject,
+            '[' => Token::BeginArray,
+            ']' => Token::EndArray,
+            ':' => Token::Colon,
+            ',' => Token::Comma,
+            '"' => {
+                let mut escaped = false;
+                let mut end = None;
+                for (index, c) in chars.by_ref() {
+                    match c {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => {
+                            end = Some(index);
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                match end {
+                    Some(end) => Token::String(decode_string(&text[start + 1..end])?, true),
+                    None => Token::String(decode_partial_string(&text[start + 1..])?, false),
+                }
+            }
+            _ => {
+                let mut end = text.len();
+                while let Some(&(index, c)) = chars.peek() {
+                    if c.is_whitespace() || "{}[]:,\"".contains(c) {
+                        end = index;
+                        break;
+                    }
+                    chars.next();
+                }
+                Token::Word(text[start..end].to_string(), end < text.len())
+            }
+        };
+        tokens.push(token);
+    }
+
+    Some(tokens)
+}
+
+/// Decodes the escape sequences of the raw contents of a JSON string.
+fn decode_string(raw: &str) -> Option<String> {
+    serde_json::from_str(&format!("\"{raw}\"")).ok()
+}
+
+/// Decodes the raw contents of an unterminated JSON string, dropping a
+/// trailing escape sequence that has not been fully received.
+fn decode_partial_string(raw: &str) -> Option<String> {
+    let mut raw = raw;
+    if let Some(index) = raw.rfind('\\') {
+        let backslashes = raw[..=index]
+            .chars()
+            .rev()
+            .take_while(|c| *c == '\\')
+            .count();
+        let escape = &raw[index..];
+        let incomplete = backslashes % 2 == 1
+            && (escape.len() == 1 || (escape.starts_with("\\u") && escape.len() < 6));
+        if incomplete {
+            raw = &raw[..index];
+        }
+    }
+    decode_string(raw)
+}
+
+/// A value parsed from the tokens, and whether it was fully received.
+enum Parsed {
+    Complete(Value),
+    Partial(Value),
+}
+
+/// A recursive descent parser that stops gracefully at the end of the tokens.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// Parses a value. Returns `None` if the tokens end before any part of
+    /// the value, or if they do not form a valid document.
+    fn value(&mut self) -> Option<Parsed> {
+        match self.next()? {
+            Token::BeginObject => self.object(),
+            Token::BeginArray => self.array(),
+            Token::String(value, true) => Some(Parsed::Complete(Value::String(value))),
+            Token::String(value, false) => Some(Parsed::Partial(Value::String(value))),
+            Token::Word(word, complete) => {
+                let value = parse_word(&word)?;
+                Some(if complete {
+                    Parsed::Complete(value)
+                } else {
+                    Parsed::Partial(value)
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn object(&mut self) -> Option<Parsed> {
+        let mut object = Map::new();
+        loop {
+            let key = match self.next() {
+                None | Some(Token::String(_, false)) => {
+                    return Some(Parsed::Partial(Value::Object(object)));
+                }
+                Some(Token::EndObject) if object.is_empty() => {
+                    return Some(Parsed::Complete(Value::Object(object)));
+                }
+                Some(Token::String(key, true)) => key,
+                _ => return None,
+            };
+            match self.next() {
+                None => return Some(Parsed::Partial(Value::Object(object))),
+                Some(Token::Colon) => {}
+                _ => return None,
+            }
+            match self.value_or_end() {
+                Ok(Parsed::Complete(value)) => {
+                    object.insert(key, value);
+                }
+                Ok(Parsed::Partial(value)) => {
+                    object.insert(key, value);
+                    return Some(Parsed::Partial(Value::Object(object)));
+                }
+                Err(true) => return Some(Parsed::Partial(Value::Object(object))),
+                Err(false) => return None,
+            }
+            match self.next() {
+                None => return Some(Parsed::Partial(Value::Object(object))),
+                Some(Token::Comma) => {}
+                Some(Token::EndObject) => return Some(Parsed::Complete(Value::Object(object))),
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self) -> Option<Parsed> {
+        let mut array = Vec::new();
+        if self.tokens.get(self.position) == Some(&Token::EndArray) {
+            self.position += 1;
+            return Some(Parsed::Complete(Value::Array(array)));
+        }
+        loop {
+            match self.value_or_end() {
+                Ok(Parsed::Complete(value)) => array.push(value),
+                Ok(Parsed::Partial(value)) => {
+                    array.push(value);
+                    return Some(Parsed::Partial(Value::Array(array)));
+                }
+                Err(true) => return Some(Parsed::Partial(Value::Array(array))),
+                Err(false) => return None,
+            }
+            match self.next() {
+                None => return Some(Parsed::Partial(Value::Array(array))),
+                Some(Token::Comma) => {}
+                Some(Token::EndArray) => return Some(Parsed::Complete(Value::Array(array))),
+                _ => return None,
+            }
+        }
+    }
+
+    /// Parses a value inside a container. Fails with `true` if the tokens end
+    /// before the value can be used, and with `false` if they are invalid.
+    fn value_or_end(&mut self) -> Result<Parsed, bool> {
+        let remaining = self.position < self.tokens.len();
+        match self.value() {
+            Some(parsed) => Ok(parsed),
+            None => Err(!remaining || self.is_incomplete_word()),
+        }
+    }
+
+    /// Returns whether the last consumed token is an incomplete word at the
+    /// end of the tokens, such as a literal that is still being received.
+    fn is_incomplete_word(&self) -> bool {
+        self.position == self.tokens.len()
+            && matches!(self.tokens.last(), Some(Token::Word(word, false))
+                if ["true", "false", "null"].iter().any(|l| l.starts_with(word.as_str()))
+                    || is_number_prefix(word))
+    }
+}
+
+/// Returns whether a word is the start of a JSON number, such as `-`, `1.`
+/// or `2e+`.
+fn is_number_prefix(word: &str) -> bool {
+    #[derive(Clone, Copy)]
+    enum State {
+        Start,
+        Minus,
+        Zero,
+        Integer,
+        Dot,
+        Fraction,
+        Exponent,
+        ExponentSign,
+        ExponentDigits,
+    }
+
+    let mut state = State::Start;
+    for c in word.chars() {
+        state = match (state, c) {
+            (State::Start, '-') => State::Minus,
+            (State::Start | State::Minus, '0') => State::Zero,
+            (State::Start | State::Minus, '1'..='9') => State::Integer,
+            (State::Integer, '0'..='9') => State::Integer,
+            (State::Zero | State::Integer, '.') => State::Dot,
+            (State::Dot | State::Fraction, '0'..='9') => State::Fraction,
+            (State::Zero | State::Integer | State::Fraction, 'e' | 'E') => State::Exponent,
+            (State::Exponent, '+' | '-') => State::ExponentSign,
+            (State::Exponent | State::ExponentSign | State::ExponentDigits, '0'..='9') => {
+                State::ExponentDigits
+            }
+            _ => return false,
+        };
+    }
+    true
+}
+
+/// Parses a number or literal.
+fn parse_word(word: &str) -> Option<Value> {
+    match word {
+        "true" => Some(Value::Bool(true)),
+        "false" => Some(Value::Bool(false)),
+        "null" => Some(Value::Null),
+        _ => serde_json::from_str::<Number>(word).ok().map(Value::Number),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_complete_document() {
+        assert_eq!(
+            parse_partial_json(r#"{"a": [1, true, null]}"#),
+            Some(json!({"a": [1, true, null]}))
+        );
+    }
+
+    #[test]
+    fn test_closes_open_containers_and_strings() {
+        assert_eq!(
+            parse_partial_json(r#"{"name": "Pan", "steps": [{"text": "Mi"#),
+            Some(json!({"name": "Pan", "steps": [{"text": "Mi"}]}))
+        );
+    }
+
+    #[test]
+    fn test_drops_incomplete_keys_and_literals() {
+        assert_eq!(parse_partial_json(r#"{"a": 1, "b"#), Some(json!({"a": 1})));
+        assert_eq!(
+            parse_partial_json(r#"{"a": 1, "b":"#),
+            Some(json!({"a": 1}))
+        );
+        assert_eq!(parse_partial_json(r#"{"a": tr"#), Some(json!({})));
+        assert_eq!(parse_partial_json(r#"[1, 2, "#), Some(json!([1, 2])));
+    }
+
+    #[test]
+    fn test_keeps_partial_numbers() {
+        assert_eq!(parse_partial_json(r#"{"a": 12"#), Some(json!({"a": 12})));
+        assert_eq!(parse_partial_json(r#"{"a": -"#), Some(json!({})));
+        assert_eq!(parse_partial_json(r#"{"a": 1."#), Some(json!({})));
+        assert_eq!(parse_partial_json(r#"{"a": 1e"#), Some(json!({})));
+        assert_eq!(parse_partial_json(r#"[2, -0."#), Some(json!([2])));
+        assert_eq!(parse_partial_json(r#"{"a": 1.5e-"#), Some(json!({})));
+        assert_eq!(parse_partial_json(r#"{"a": 1.5"#), Some(json!({"a": 1.5})));
+    }
+
+    #[test]
+    fn test_rejects_words_that_cannot_become_numbers() {
+        assert_eq!(parse_partial_json(r#"{"a": 1x"#), None);
+        assert_eq!(parse_partial_json(r#"{"a": 01"#), None);
+        assert_eq!(parse_partial_json(r#"{"a": tx"#), None);
+    }
+
+    #[test]
+    fn test_drops_incomplete_escape_sequences() {
+        assert_eq!(parse_partial_json(r#"["a\"#), Some(json!(["a"])));
+        assert_eq!(parse_partial_json(r#"["a\u00"#), Some(json!(["a"])));
+        assert_eq!(parse_partial_json(r#"["a\n"#), Some(json!(["a\n"])));
+    }
+
+    #[test]
+    fn test_rejects_invalid_and_empty_input() {
+        assert_eq!(parse_partial_json(""), None);
+        assert_eq!(parse_partial_json("{]"), None);
+        assert_eq!(parse_partial_json(r#"{"a" 1}"#), None);
+    }
+}
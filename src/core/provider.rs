@@ -8,7 +8,9 @@ use crate::core::language_model::LanguageModel;
 /// A marker trait representing a fully configured AI provider.
 ///
 /// The `Provider` trait aggregates all necessary capabilities for a given AI provider,
-/// such as `LanguageModel`, and in the future, potentially `ImageModel` or `EmbeddingModel`.
+/// such as `LanguageModel`. Embedding, image, speech and transcription models
+/// are served by separate types implementing `EmbeddingModel`, `ImageModel`,
+/// `SpeechModel` and `TranscriptionModel`.
 ///
 /// By implementing `Provider`, a type signals that it is a complete and ready-to-use
 /// client for interacting with a specific AI service.
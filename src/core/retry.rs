@@ -0,0 +1,213 @@
+//! Defines the `RetryPolicy` that providers use to retry failed requests.
+//!
+//! Requests that fail with a retryable error, such as a rate limit or a
+//! server error, are retried with exponential backoff. The delay doubles with
+//! every attempt up to a maximum, is randomized to spread out the retries of
+//! concurrent clients, and follows the `Retry-After` header when the API
+//! sends one.
+
+use std::future::Future;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// A policy for retrying requests that fail with a retryable error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first. Set it to 1 to
+    /// disable retries.
+    #[builder(default = "3", setter(into = false))]
+    pub max_attempts: u32,
+
+    /// The delay before the first retry, doubled for every further retry.
+    #[builder(default = "Duration::from_millis(500)", setter(into = false))]
+    pub base_delay: Duration,
+
+    /// The maximum delay between two attempts, which also caps the delay
+    /// requested with `Retry-After`.
+    #[builder(default = "Duration::from_secs(30)", setter(into = false))]
+    pub max_delay: Duration,
+
+    /// Whether to randomize the delay to between half and all of it.
+    #[builder(default = "true", setter(into = false))]
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a new builder for `RetryPolicy`.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// Returns a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the delay before retrying after the given attempt, counted
+    /// from 1, failed with the given error.
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        if let Some(retry_after) = error.retry_after() {
+            return retry_after.min(self.max_delay);
+        }
+
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        match self.jitter {
+            true => delay.mul_f64(rand::random_range(0.5..=1.0)),
+            false => delay,
+        }
+    }
+
+    /// Runs an operation, running it again after a delay while it fails with
+    /// a retryable error and attempts remain.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt.
+    pub async fn retry<T, F, Fut>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(err) if err.is_retryable() && attempt < self.max_attempts => {
+                    let delay = self.delay(attempt, &err);
+                    log::warn!(
+                        "Attempt {attempt} of {} failed, retrying in {delay:?}: {err}",
+                        self.max_attempts
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder()
+            .build()
+            .expect("all retry policy fields have defaults")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiErrorDetails;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn server_error() -> Error {
+        Error::ServerError(ApiErrorDetails::default())
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::builder()
+            .max_attempts(max_attempts)
+            .base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_delay_backs_off_exponentially() {
+        let policy = RetryPolicy::builder()
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .jitter(false)
+            .build()
+            .unwrap();
+
+        let delays: Vec<_> = (1..=4)
+            .map(|attempt| policy.delay(attempt, &server_error()).as_secs())
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn test_delay_applies_jitter() {
+        let policy = RetryPolicy::builder()
+            .base_delay(Duration::from_secs(8))
+            .build()
+            .unwrap();
+
+        for _ in 0..100 {
+            let delay = policy.delay(1, &server_error());
+            assert!(delay >= Duration::from_secs(4) && delay <= Duration::from_secs(8));
+        }
+    }
+
+    #[test]
+    fn test_delay_honors_retry_after() {
+        let policy = RetryPolicy::builder()
+            .max_delay(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        let rate_limited = |seconds| Error::RateLimitError {
+            details: ApiErrorDetails::default(),
+            retry_after: Some(Duration::from_secs(seconds)),
+        };
+
+        assert_eq!(policy.delay(1, &rate_limited(7)), Duration::from_secs(7));
+        assert_eq!(policy.delay(1, &rate_limited(60)), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_retry_retries_retryable_errors() {
+        let attempts = AtomicU32::new(0);
+
+        let result = policy(3)
+            .retry(|| async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(server_error()),
+                    attempt => Ok(attempt),
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<()> = policy(3)
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(server_error())
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::ServerError(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_does_not_retry_other_errors() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<()> = policy(3)
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(Error::InvalidRequestError(ApiErrorDetails::default()))
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,40 @@
+//! Defines the `SpeechModel` trait for models that turn text into speech.
+
+use futures::{StreamExt, stream};
+
+use crate::core::types::{SpeechModelCallOptions, SpeechModelResponse, StreamSpeechResponse};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// The core trait abstracting the capabilities of a speech model.
+#[async_trait]
+pub trait SpeechModel: Send + Sync + std::fmt::Debug {
+    /// Returns the identifier of the model (e.g., "gpt-4o-mini-tts").
+    fn model_name(&self) -> &str;
+
+    /// Returns the name of the provider (e.g., "openai").
+    fn provider_name(&self) -> &str;
+
+    /// Generates the complete audio of the speech.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails or the request is invalid.
+    async fn generate(&self, options: SpeechModelCallOptions) -> Result<SpeechModelResponse>;
+
+    /// Streams the audio of the speech as it is generated.
+    ///
+    /// The default implementation generates the complete audio and returns
+    /// it as a single chunk, for providers that cannot stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails before streaming begins.
+    async fn stream(&self, options: SpeechModelCallOptions) -> Result<StreamSpeechResponse> {
+        let response = self.generate(options).await?;
+        Ok(StreamSpeechResponse {
+            stream: stream::once(async { Ok(response.audio) }).boxed(),
+            media_type: response.media_type,
+        })
+    }
+}
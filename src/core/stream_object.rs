@@ -0,0 +1,190 @@
+//! Provides the user-facing function for streaming structured object generation.
+//!
+//! This module contains the `stream_object` function, which mirrors
+//! `generate_object` but yields the object as it is being generated, parsing
+//! the incomplete JSON received so far after every text delta.
+
+use futures::{StreamExt, stream};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    core::{
+        generate_object::{ObjectSchema, extract_json, object_call_options},
+        language_model::LanguageModel,
+        partial_json::parse_partial_json,
+        types::{
+            LanguageModelStream, LanguageModelStreamChunk, ObjectStreamChunk,
+            StreamObjectCallOptions, StreamObjectResponse,
+        },
+    },
+    error::Result,
+};
+
+/// Streams an object of type `T` using a specified language model.
+///
+/// The schema of `T` is sent to the model in the same way as in
+/// `generate_object`. While the model responds, an `ObjectStreamChunk::Partial`
+/// is emitted every time the parsed object changes. Once the model finishes,
+/// the complete response is deserialized into `T` and emitted as an
+/// `ObjectStreamChunk::Object`, or as an `ObjectStreamChunk::Error` if it does
+/// not match the type.
+///
+/// # Arguments
+///
+/// * `model` - A language model that implements the `LanguageModel` trait.
+///
+/// * `options` - A `StreamObjectCallOptions` struct containing the system
+///   prompt, the prompt or conversation history, and schema metadata.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to start the stream.
+pub async fn stream_object<T: DeserializeOwned + JsonSchema + Send + 'static>(
+    model: impl LanguageModel,
+    options: StreamObjectCallOptions,
+) -> Result<StreamObjectResponse<T>> {
+    let schema = ObjectSchema::of::<T>();
+    let call_options = object_call_options::<T>(&model, &options.into(), &schema);
+
+    let stream = model.stream(call_options).await?;
+
+    Ok(StreamObjectResponse::new(
+        object_stream(stream, schema).boxed(),
+    ))
+}
+
+/// The state of an object stream between chunks.
+struct ObjectStreamState {
+    chunks: Option<LanguageModelStream>,
+    schema: ObjectSchema,
+    text: String,
+    partial: Option<Value>,
+}
+
+/// Turns a stream of text chunks into a stream of partial objects followed
+/// by the final object.
+fn object_stream<T: DeserializeOwned>(
+    chunks: LanguageModelStream,
+    schema: ObjectSchema,
+) -> impl futures::Stream<Item = ObjectStreamChunk<T>> {
+    let state = ObjectStreamState {
+        chunks: Some(chunks),
+        schema,
+        text: String::new(),
+        partial: None,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            let chunks = state.chunks.as_mut()?;
+            match chunks.next().await {
+                Some(LanguageModelStreamChunk::TextDelta(delta)) => {
+                    state.text.push_str(&delta);
+                    if let Some(partial) = parse_partial_json(extract_json(&state.text))
+                        .and_then(|partial| state.schema.unwrap_value(partial))
+                        && state.partial.as_ref() != Some(&partial)
+                    {
+                        state.partial = Some(partial.clone());
+                        return Some((ObjectStreamChunk::Partial(partial), state));
+                    }
+                }
+                Some(LanguageModelStreamChunk::Error(error)) => {
+                    state.chunks = None;
+                    return Some((ObjectStreamChunk::Error(error), state));
+                }
+                Some(_) => {}
+                None => {
+                    state.chunks = None;
+                    let chunk = match state.schema.parse(&state.text) {
+                        Ok(object) => ObjectStreamChunk::Object(object),
+                        Err(err) => ObjectStreamChunk::Error(err.to_string()),
+                    };
+                    return Some((chunk, state));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::FinishReason;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+    struct Recipe {
+        name: String,
+        steps: Vec<String>,
+    }
+
+    fn text_stream(deltas: &[&str]) -> LanguageModelStream {
+        let chunks: Vec<_> = deltas
+            .iter()
+            .map(|delta| LanguageModelStreamChunk::TextDelta(delta.to_string()))
+            .chain([LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            }])
+            .collect();
+        stream::iter(chunks).boxed()
+    }
+
+    #[tokio::test]
+    async fn test_object_stream_emits_partials_and_final_object() {
+        let stream = text_stream(&[r#"{"name": "Pan"#, r#"cakes", "st"#, r#"eps": ["Mix"]}"#]);
+
+        let chunks: Vec<ObjectStreamChunk<Recipe>> =
+            object_stream(stream, ObjectSchema::of::<Recipe>())
+                .collect()
+                .await;
+
+        assert_eq!(
+            chunks,
+            vec![
+                ObjectStreamChunk::Partial(serde_json::json!({"name": "Pan"})),
+                ObjectStreamChunk::Partial(serde_json::json!({"name": "Pancakes"})),
+                ObjectStreamChunk::Partial(
+                    serde_json::json!({"name": "Pancakes", "steps": ["Mix"]})
+                ),
+                ObjectStreamChunk::Object(Recipe {
+                    name: "Pancakes".to_string(),
+                    steps: vec!["Mix".to_string()],
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_stream_unwraps_non_object_schemas() {
+        let stream = text_stream(&[r#"{"elements": ["Mi"#, r#"x", "Fry"]}"#]);
+
+        let chunks: Vec<ObjectStreamChunk<Vec<String>>> =
+            object_stream(stream, ObjectSchema::of::<Vec<String>>())
+                .collect()
+                .await;
+
+        assert_eq!(
+            chunks,
+            vec![
+                ObjectStreamChunk::Partial(serde_json::json!(["Mi"])),
+                ObjectStreamChunk::Partial(serde_json::json!(["Mix", "Fry"])),
+                ObjectStreamChunk::Object(vec!["Mix".to_string(), "Fry".to_string()]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_stream_reports_invalid_object() {
+        let stream = text_stream(&[r#"{"name": "Pancakes"}"#]);
+
+        let chunks: Vec<ObjectStreamChunk<Recipe>> =
+            object_stream(stream, ObjectSchema::of::<Recipe>())
+                .collect()
+                .await;
+
+        assert_eq!(chunks.len(), 2);
+        assert!(matches!(chunks[1], ObjectStreamChunk::Error(_)));
+    }
+}
@@ -0,0 +1,65 @@
+//! Provides the primary user-facing function for streaming text generation.
+//!
+//! This module contains the `stream_text` function, which mirrors
+//! `generate_text` but returns the model's output incrementally as it is
+//! produced, using any model that implements the `LanguageModel` trait.
+
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        types::{StreamTextCallOptions, StreamTextResponse},
+    },
+    error::Result,
+};
+
+/// Streams text using a specified language model.
+///
+/// This function orchestrates the streaming process by taking a configured
+/// set of options, invoking the `stream` method on the provided language model,
+/// and returning a response wrapping the stream of chunks.
+///
+/// # Arguments
+///
+/// * `model` - A language model that implements the `LanguageModel` trait.
+///
+/// * `options` - A `StreamTextCallOptions` struct containing the system prompt,
+///   the prompt or conversation history, and other parameters for the request.
+///
+/// # Errors
+///
+/// Returns an `Error` if the underlying model fails to start the stream.
+pub async fn stream_text(
+    model: impl LanguageModel,
+    options: StreamTextCallOptions,
+) -> Result<StreamTextResponse> {
+    let mut call_options = options.call_options();
+    call_options.tools = options.tools.iter().map(|tool| tool.definition()).collect();
+
+    let stream = model.stream(call_options).await?;
+
+    Ok(StreamTextResponse::new(stream))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockLanguageModel;
+    use crate::core::types::LanguageModelResponse;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_stream_text_streams_model_output() {
+        let model = MockLanguageModel::new([LanguageModelResponse::new("Hello there!")]);
+        let options = StreamTextCallOptions::builder()
+            .system("be brief")
+            .prompt("Say hello")
+            .build()
+            .unwrap();
+
+        let response = stream_text(&model, options).await.unwrap();
+        let text: String = response.text_stream().collect().await;
+
+        assert_eq!(text, "Hello there!");
+        assert_eq!(model.calls()[0].system.as_deref(), Some("be brief"));
+    }
+}
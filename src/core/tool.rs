@@ -0,0 +1,75 @@
+//! Defines the `Tool` trait for giving language models access to functions.
+//!
+//! Tools are described to the model by a name, a description and a JSON Schema
+//! of their parameters. When the model decides to call a tool, the arguments
+//! it produced are passed to `Tool::execute`.
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::core::types::ToolDefinition;
+use crate::error::Result;
+
+/// A function that a language model can call.
+///
+/// # Examples
+///
+/// ```
+/// use ai_sdk_rs::core::Tool;
+/// use ai_sdk_rs::error::Result;
+/// use async_trait::async_trait;
+/// use serde_json::{Value, json};
+///
+/// #[derive(Debug)]
+/// struct Weather;
+///
+/// #[async_trait]
+/// impl Tool for Weather {
+///     fn name(&self) -> &str {
+///         "get_weather"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "Get the current weather for a city."
+///     }
+///
+///     fn parameters(&self) -> Value {
+///         json!({
+///             "type": "object",
+///             "properties": { "city": { "type": "string" } },
+///             "required": ["city"]
+///         })
+///     }
+///
+///     async fn execute(&self, arguments: Value) -> Result<Value> {
+///         Ok(json!({ "city": arguments["city"], "temperature": 21 }))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Tool: Send + Sync + std::fmt::Debug {
+    /// Returns the name the model uses to call the tool.
+    fn name(&self) -> &str;
+
+    /// Returns a description of what the tool does and when to use it.
+    fn description(&self) -> &str;
+
+    /// Returns the JSON Schema of the tool's parameters.
+    fn parameters(&self) -> Value;
+
+    /// Executes the tool with the arguments produced by the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the arguments are invalid or the tool fails.
+    async fn execute(&self, arguments: Value) -> Result<Value>;
+
+    /// Returns the definition of the tool that is sent to the model.
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            parameters: self.parameters(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Provides the user-facing function for speech-to-text transcription.
+//!
+//! This module contains the `transcribe` function, which reads the audio
+//! from memory or a file, determines its media type and transcribes it using
+//! any model that implements the `TranscriptionModel` trait.
+
+use crate::{
+    core::{
+        media_type,
+        transcription_model::TranscriptionModel,
+        types::{
+            AudioInput, TranscribeCallOptions, TranscribeResponse, TranscriptionModelCallOptions,
+        },
+    },
+    error::{Error, Result},
+};
+
+/// Transcribes audio using a specified transcription model.
+///
+/// # Arguments
+///
+/// * `model` - A transcription model that implements the `TranscriptionModel`
+///   trait.
+///
+/// * `options` - A `TranscribeCallOptions` struct containing the audio bytes
+///   or file path, and optionally its language.
+///
+/// # Errors
+///
+/// Returns an `Error` if the audio file cannot be read, its media type
+/// cannot be determined, or the underlying model fails to transcribe it.
+pub async fn transcribe(
+    model: impl TranscriptionModel,
+    options: TranscribeCallOptions,
+) -> Result<TranscribeResponse> {
+    let (audio, media_type) = match options.audio {
+        AudioInput::Bytes(audio) => {
+            let media_type = media_type::detect(&audio);
+            (audio, media_type)
+        }
+        AudioInput::Path(path) => {
+            let audio = tokio::fs::read(&path)
+                .await
+                .map_err(|err| Error::Other(format!("failed to read {}: {err}", path.display())))?;
+            let media_type = media_type::from_path(&path).or_else(|| media_type::detect(&audio));
+            (audio, media_type)
+        }
+    };
+    let media_type = media_type.ok_or_else(|| {
+        Error::Other("could not determine the media type of the audio".to_string())
+    })?;
+
+    let response = model
+        .transcribe(TranscriptionModelCallOptions {
+            audio,
+            media_type: media_type.to_string(),
+            language: options.language,
+            prompt: options.prompt,
+        })
+        .await?;
+
+    Ok(TranscribeResponse {
+        text: response.text,
+        language: response.language,
+        duration: response.duration,
+        segments: response.segments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockTranscriptionModel;
+
+    fn options(audio: impl Into<AudioInput>) -> TranscribeCallOptions {
+        TranscribeCallOptions::builder()
+            .audio(audio)
+            .language("en")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_detects_media_type_of_bytes() {
+        let model = MockTranscriptionModel::default();
+
+        let response = transcribe(&model, options(b"fLaC\x00\x00".to_vec()))
+            .await
+            .unwrap();
+
+        assert_eq!(response.text, "audio/flac");
+        assert_eq!(model.calls()[0].audio, b"fLaC\x00\x00");
+        assert_eq!(response.language.as_deref(), Some("en"));
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_reads_file() {
+        let file = tempfile::Builder::new().suffix(".mp3").tempfile().unwrap();
+        std::fs::write(file.path(), b"\xFF\xFB\x90\x00").unwrap();
+
+        let model = MockTranscriptionModel::default();
+
+        let response = transcribe(&model, options(file.path())).await.unwrap();
+
+        assert_eq!(response.text, "audio/mpeg");
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_rejects_unknown_media_type() {
+        let model = MockTranscriptionModel::default();
+
+        let result = transcribe(&model, options(b"hello".to_vec())).await;
+
+        assert!(result.is_err());
+        assert!(model.calls().is_empty());
+    }
+}
@@ -0,0 +1,26 @@
+//! Defines the `TranscriptionModel` trait for models that turn speech into
+//! text.
+
+use crate::core::types::{TranscriptionModelCallOptions, TranscriptionModelResponse};
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// The core trait abstracting the capabilities of a transcription model.
+#[async_trait]
+pub trait TranscriptionModel: Send + Sync + std::fmt::Debug {
+    /// Returns the identifier of the model (e.g., "whisper-1").
+    fn model_name(&self) -> &str;
+
+    /// Returns the name of the provider (e.g., "openai").
+    fn provider_name(&self) -> &str;
+
+    /// Transcribes an audio file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the API call fails or the request is invalid.
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelCallOptions,
+    ) -> Result<TranscriptionModelResponse>;
+}
@@ -1,16 +1,434 @@
 //! Core types for AI SDK functions.
 
 use derive_builder::Builder;
+use futures::{Stream, StreamExt, stream::BoxStream};
 use serde::{Deserialize, Serialize};
 
+use crate::core::media_type;
+use crate::core::retry::RetryPolicy;
+use crate::core::tool::Tool;
 use crate::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The role of the author of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Instructions that guide the model's behavior.
+    System,
+    /// Input from the end user.
+    User,
+    /// Output previously generated by the model.
+    Assistant,
+    /// The result of a tool call.
+    Tool,
+}
+
+/// A provider-neutral message in a conversation with a language model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "role", rename_all = "lowercase")]
+pub enum Message {
+    /// A system message.
+    System {
+        /// The instructions for the model.
+        content: String,
+    },
+    /// A user message.
+    User {
+        /// The parts of the message, such as text and images.
+        content: Vec<ContentPart>,
+    },
+    /// An assistant message.
+    Assistant {
+        /// The text of the message.
+        content: String,
+        /// The tool calls requested by the model in this message.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tool_calls: Vec<ToolCall>,
+    },
+    /// A tool message carrying the result of a tool call.
+    Tool {
+        /// The id of the tool call this message is a result for.
+        tool_call_id: String,
+        /// The result of the tool call.
+        content: String,
+        /// Whether the tool call failed, in which case the content describes
+        /// the failure.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        is_error: bool,
+    },
+}
+
+impl Message {
+    /// Creates a new system message.
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::System {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a new user message.
+    pub fn user(content: impl Into<String>) -> Self {
+        Self::User {
+            content: vec![ContentPart::text(content)],
+        }
+    }
+
+    /// Creates a new user message with the given content parts, e.g. a
+    /// question along with the image it is about.
+    pub fn user_with_parts(content: impl Into<Vec<ContentPart>>) -> Self {
+        Self::User {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a new assistant message.
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self::Assistant {
+            content: content.into(),
+            tool_calls: Vec::new(),
+        }
+    }
+
+    /// Creates a new tool message with the result of the given tool call.
+    pub fn tool(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::Tool {
+            tool_call_id: tool_call_id.into(),
+            content: content.into(),
+            is_error: false,
+        }
+    }
+
+    /// Creates a new tool message reporting that the given tool call failed.
+    pub fn tool_error(tool_call_id: impl Into<String>, error: impl Into<String>) -> Self {
+        Self::Tool {
+            tool_call_id: tool_call_id.into(),
+            content: error.into(),
+            is_error: true,
+        }
+    }
+
+    /// Creates a new assistant message requesting the given tool calls.
+    pub fn assistant_with_tool_calls(
+        content: impl Into<String>,
+        tool_calls: impl Into<Vec<ToolCall>>,
+    ) -> Self {
+        Self::Assistant {
+            content: content.into(),
+            tool_calls: tool_calls.into(),
+        }
+    }
+
+    /// Returns the role of the author of the message.
+    pub fn role(&self) -> Role {
+        match self {
+            Self::System { .. } => Role::System,
+            Self::User { .. } => Role::User,
+            Self::Assistant { .. } => Role::Assistant,
+            Self::Tool { .. } => Role::Tool,
+        }
+    }
+}
+
+/// A part of the content of a user message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    /// Text.
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// An image.
+    Image {
+        /// Where the image is loaded from.
+        source: ImageSource,
+    },
+    /// An audio file.
+    Audio {
+        /// The encoded audio file.
+        data: Vec<u8>,
+        /// The media type of the audio, e.g. `"audio/wav"`.
+        media_type: String,
+    },
+    /// A file, such as a PDF document.
+    File {
+        /// The content of the file.
+        data: Vec<u8>,
+        /// The media type of the file, e.g. `"application/pdf"`.
+        media_type: String,
+        /// The name of the file.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+    },
+}
+
+impl ContentPart {
+    /// Creates a new text part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Creates a new image part for an image the provider downloads.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::Image {
+            source: ImageSource::Url { url: url.into() },
+        }
+    }
+
+    /// Creates a new image part for an encoded image, e.g. a PNG file.
+    pub fn image(data: impl Into<Vec<u8>>, media_type: impl Into<String>) -> Self {
+        Self::Image {
+            source: ImageSource::Bytes {
+                data: data.into(),
+                media_type: media_type.into(),
+            },
+        }
+    }
+
+    /// Creates a new audio part.
+    pub fn audio(data: impl Into<Vec<u8>>, media_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            media_type: media_type.into(),
+        }
+    }
+
+    /// Creates a new file part.
+    pub fn file(data: impl Into<Vec<u8>>, media_type: impl Into<String>) -> Self {
+        Self::File {
+            data: data.into(),
+            media_type: media_type.into(),
+            filename: None,
+        }
+    }
+
+    /// Reads a local file into an image, audio or file part, depending on its
+    /// media type. The media type is looked up from the extension, falling
+    /// back to detection from the content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the file cannot be read.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let data = tokio::fs::read(path)
+            .await
+            .map_err(|err| Error::Other(format!("failed to read {}: {err}", path.display())))?;
+        let media_type = media_type::from_path(path)
+            .or_else(|| media_type::detect(&data))
+            .unwrap_or("application/octet-stream");
+
+        Ok(match media_type.split('/').next() {
+            Some("image") => Self::image(data, media_type),
+            Some("audio") => Self::audio(data, media_type),
+            _ => Self::File {
+                data,
+                media_type: media_type.to_string(),
+                filename: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned()),
+            },
+        })
+    }
+
+    /// Returns the text of a text part.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text { text } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for ContentPart {
+    fn from(text: String) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<&str> for ContentPart {
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+/// Where the image of an image part is loaded from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageSource {
+    /// An image the provider downloads.
+    Url {
+        /// The URL of the image.
+        url: String,
+    },
+    /// An encoded image sent along with the request.
+    Bytes {
+        /// The encoded image.
+        data: Vec<u8>,
+        /// The media type of the image, e.g. `"image/png"`.
+        media_type: String,
+    },
+}
+
+/// Settings that control sampling and the handling of a language model call.
+/// Shared by the options of all text and object generation functions, whose
+/// builders accept them directly, e.g. `.temperature(0.2)`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct CallSettings {
+    /// The sampling temperature. Higher values make the output more random.
+    #[builder(default, setter(into = false, strip_option))]
+    pub temperature: Option<f32>,
+
+    /// Nucleus sampling: only tokens within this cumulative probability are
+    /// considered.
+    #[builder(default, setter(into = false, strip_option))]
+    pub top_p: Option<f32>,
+
+    /// Only sample from the top K most likely tokens.
+    #[builder(default, setter(into = false, strip_option))]
+    pub top_k: Option<u32>,
+
+    /// Penalizes tokens that already appear in the text, encouraging new topics.
+    #[builder(default, setter(into = false, strip_option))]
+    pub presence_penalty: Option<f32>,
+
+    /// Penalizes tokens proportionally to how often they already appear.
+    #[builder(default, setter(into = false, strip_option))]
+    pub frequency_penalty: Option<f32>,
+
+    /// Sequences that stop the generation when produced.
+    #[builder(default)]
+    pub stop_sequences: Vec<String>,
+
+    /// The seed for deterministic sampling, if supported by the provider.
+    #[builder(default, setter(into = false, strip_option))]
+    pub seed: Option<u64>,
+
+    /// The maximum number of tokens to generate. Overrides the provider's default.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// The policy for retrying failed requests. Overrides the provider's
+    /// policy.
+    #[builder(default, setter(strip_option))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+impl CallSettings {
+    /// Creates a new builder for `CallSettings`.
+    pub fn builder() -> CallSettingsBuilder {
+        CallSettingsBuilder::default()
+    }
+}
+
+/// Implements setters for the fields of `CallSettings` on the builder of an
+/// options struct with a `settings` field.
+macro_rules! call_settings_setters {
+    ($($builder:ty),+) => {$(
+        impl $builder {
+            /// Sets the sampling temperature. See [`CallSettings::temperature`].
+            pub fn temperature(self, temperature: f32) -> Self {
+                self.with_settings(|settings| settings.temperature = Some(temperature))
+            }
+
+            /// Sets nucleus sampling. See [`CallSettings::top_p`].
+            pub fn top_p(self, top_p: f32) -> Self {
+                self.with_settings(|settings| settings.top_p = Some(top_p))
+            }
+
+            /// Sets top K sampling. See [`CallSettings::top_k`].
+            pub fn top_k(self, top_k: u32) -> Self {
+                self.with_settings(|settings| settings.top_k = Some(top_k))
+            }
+
+            /// Sets the presence penalty. See [`CallSettings::presence_penalty`].
+            pub fn presence_penalty(self, presence_penalty: f32) -> Self {
+                self.with_settings(|settings| settings.presence_penalty = Some(presence_penalty))
+            }
+
+            /// Sets the frequency penalty. See [`CallSettings::frequency_penalty`].
+            pub fn frequency_penalty(self, frequency_penalty: f32) -> Self {
+                self.with_settings(|settings| settings.frequency_penalty = Some(frequency_penalty))
+            }
+
+            /// Sets the stop sequences. See [`CallSettings::stop_sequences`].
+            pub fn stop_sequences(self, stop_sequences: impl Into<Vec<String>>) -> Self {
+                self.with_settings(|settings| settings.stop_sequences = stop_sequences.into())
+            }
+
+            /// Sets the seed. See [`CallSettings::seed`].
+            pub fn seed(self, seed: u64) -> Self {
+                self.with_settings(|settings| settings.seed = Some(seed))
+            }
+
+            /// Sets the maximum number of tokens. See [`CallSettings::max_tokens`].
+            pub fn max_tokens(self, max_tokens: u32) -> Self {
+                self.with_settings(|settings| settings.max_tokens = Some(max_tokens))
+            }
+
+            /// Sets the retry policy. See [`CallSettings::retry_policy`].
+            pub fn retry_policy(self, retry_policy: impl Into<RetryPolicy>) -> Self {
+                self.with_settings(|settings| settings.retry_policy = Some(retry_policy.into()))
+            }
+
+            fn with_settings(mut self, update: impl FnOnce(&mut CallSettings)) -> Self {
+                update(self.settings.get_or_insert_with(CallSettings::default));
+                self
+            }
+        }
+    )+};
+}
+
+call_settings_setters!(
+    GenerateTextCallOptionsBuilder,
+    StreamTextCallOptionsBuilder,
+    GenerateObjectCallOptionsBuilder,
+    StreamObjectCallOptionsBuilder,
+    LanguageModelCallOptionsBuilder
+);
 
 /// Options for a `generate_text` call.
+///
+/// Either `prompt` or `messages` must be set. When both are given, the
+/// prompt is appended to the conversation as a final user message.
 #[derive(Debug, Clone, Serialize, Deserialize, Builder)]
-#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+#[builder(
+    pattern = "owned",
+    setter(into),
+    build_fn(error = "Error", validate = "Self::validate")
+)]
 pub struct GenerateTextCallOptions {
+    /// The system prompt to guide the model's behavior.
+    #[builder(default, setter(strip_option))]
+    pub system: Option<String>,
+
     /// The prompt to generate text from.
-    pub prompt: String,
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+
+    /// The conversation history to generate text from.
+    #[builder(default)]
+    pub messages: Vec<Message>,
+
+    /// The tools the model may call.
+    #[serde(skip)]
+    #[builder(default)]
+    pub tools: Vec<Arc<dyn Tool>>,
+
+    /// The maximum number of model calls to make. Tool calls requested by the
+    /// model are executed and their results fed back to it until it answers
+    /// without calling a tool or this limit is reached.
+    #[builder(default = "1", setter(into = false))]
+    pub max_steps: u32,
+
+    /// The sampling settings of the call and the policy for retrying it.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub settings: CallSettings,
 }
 
 impl GenerateTextCallOptions {
@@ -18,28 +436,425 @@ impl GenerateTextCallOptions {
     pub fn builder() -> GenerateTextCallOptionsBuilder {
         GenerateTextCallOptionsBuilder::default()
     }
+
+    /// Returns the language model call options for this call, with the prompt
+    /// appended to the conversation.
+    pub(crate) fn call_options(&self) -> LanguageModelCallOptions {
+        call_options(&self.system, &self.prompt, &self.messages, &self.settings)
+    }
+}
+
+impl GenerateTextCallOptionsBuilder {
+    fn validate(&self) -> Result<(), Error> {
+        validate_prompt(&self.prompt, &self.messages)
+    }
+    /// Adds a tool the model may call.
+    pub fn tool(mut self, tool: impl Tool + 'static) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(Arc::new(tool));
+        self
+    }
+}
+
+/// Builds the language model call options for a call, appending the prompt,
+/// if any, to the messages as a final user message.
+fn call_options(
+    system: &Option<String>,
+    prompt: &Option<String>,
+    messages: &[Message],
+    settings: &CallSettings,
+) -> LanguageModelCallOptions {
+    let mut messages = messages.to_vec();
+    if let Some(prompt) = prompt {
+        messages.push(Message::user(prompt));
+    }
+    LanguageModelCallOptions {
+        system: system.clone(),
+        messages,
+        tools: Vec::new(),
+        response_format: None,
+        settings: settings.clone(),
+    }
+}
+
+/// Ensures that a prompt or at least one message was provided to a builder.
+fn validate_prompt(
+    prompt: &Option<Option<String>>,
+    messages: &Option<Vec<Message>>,
+) -> Result<(), Error> {
+    let has_prompt = matches!(prompt, Some(Some(_)));
+    let has_messages = messages.as_ref().is_some_and(|m| !m.is_empty());
+    if has_prompt || has_messages {
+        Ok(())
+    } else {
+        Err(Error::MissingField("prompt or messages".to_string()))
+    }
 }
 
 /// Response from a `generate_text` call.
 #[derive(Debug)]
 pub struct GenerateTextResponse {
-    /// The generated text.
+    /// The generated text of the final step.
     pub text: String,
+
+    /// The tool calls requested by the model in the final step.
+    pub tool_calls: Vec<ToolCall>,
+
+    /// The results of the tool calls executed in the final step.
+    pub tool_results: Vec<ToolResult>,
+
+    /// The reason the model stopped generating in the final step.
+    pub finish_reason: FinishReason,
+
+    /// The token usage summed over all steps, if reported by the provider.
+    pub usage: Option<Usage>,
+
+    /// Every step of the generation, in order.
+    pub steps: Vec<StepResult>,
 }
 
 impl GenerateTextResponse {
     /// Creates a new response with the generated text.
     pub fn new(text: impl Into<String>) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: text.into(),
+            tool_calls: Vec::new(),
+            tool_results: Vec::new(),
+            finish_reason: FinishReason::Stop,
+            usage: None,
+            steps: Vec::new(),
+        }
+    }
+}
+
+/// The outcome of a single model call in a `generate_text` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepResult {
+    /// The text generated in this step.
+    pub text: String,
+
+    /// The tool calls requested by the model in this step.
+    pub tool_calls: Vec<ToolCall>,
+
+    /// The results of the tool calls executed in this step.
+    pub tool_results: Vec<ToolResult>,
+
+    /// The reason the model stopped generating in this step.
+    pub finish_reason: FinishReason,
+
+    /// The token usage of this step, if reported by the provider.
+    pub usage: Option<Usage>,
+}
+
+/// Options for a `stream_text` call.
+///
+/// Either `prompt` or `messages` must be set. When both are given, the
+/// prompt is appended to the conversation as a final user message.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into),
+    build_fn(error = "Error", validate = "Self::validate")
+)]
+pub struct StreamTextCallOptions {
+    /// The system prompt to guide the model's behavior.
+    #[builder(default, setter(strip_option))]
+    pub system: Option<String>,
+
+    /// The prompt to generate text from.
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+
+    /// The conversation history to generate text from.
+    #[builder(default)]
+    pub messages: Vec<Message>,
+
+    /// The tools the model may call.
+    #[serde(skip)]
+    #[builder(default)]
+    pub tools: Vec<Arc<dyn Tool>>,
+
+    /// The sampling settings of the call and the policy for retrying it.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub settings: CallSettings,
+}
+
+impl StreamTextCallOptions {
+    /// Creates a new builder for `StreamTextCallOptions`.
+    pub fn builder() -> StreamTextCallOptionsBuilder {
+        StreamTextCallOptionsBuilder::default()
+    }
+
+    /// Returns the language model call options for this call, with the prompt
+    /// appended to the conversation.
+    pub(crate) fn call_options(&self) -> LanguageModelCallOptions {
+        call_options(&self.system, &self.prompt, &self.messages, &self.settings)
+    }
+}
+
+impl StreamTextCallOptionsBuilder {
+    fn validate(&self) -> Result<(), Error> {
+        validate_prompt(&self.prompt, &self.messages)
+    }
+    /// Adds a tool the model may call.
+    pub fn tool(mut self, tool: impl Tool + 'static) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(Arc::new(tool));
+        self
+    }
+}
+
+/// Response from a `stream_text` call.
+pub struct StreamTextResponse {
+    /// The stream of chunks produced by the model.
+    pub stream: LanguageModelStream,
+}
+
+impl StreamTextResponse {
+    /// Creates a new response wrapping the given stream.
+    pub fn new(stream: LanguageModelStream) -> Self {
+        Self { stream }
+    }
+
+    /// Consumes the response and returns a stream of only the text deltas.
+    pub fn text_stream(self) -> impl Stream<Item = String> + Send {
+        self.stream.filter_map(|chunk| async move {
+            match chunk {
+                LanguageModelStreamChunk::TextDelta(text) => Some(text),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for StreamTextResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamTextResponse").finish_non_exhaustive()
+    }
+}
+
+/// Options for a `generate_object` call.
+///
+/// Either `prompt` or `messages` must be set. When both are given, the
+/// prompt is appended to the conversation as a final user message.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into),
+    build_fn(error = "Error", validate = "Self::validate")
+)]
+pub struct GenerateObjectCallOptions {
+    /// The system prompt to guide the model's behavior.
+    #[builder(default, setter(strip_option))]
+    pub system: Option<String>,
+
+    /// The prompt to generate the object from.
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+
+    /// The conversation history to generate the object from.
+    #[builder(default)]
+    pub messages: Vec<Message>,
+
+    /// The name of the schema, used by providers that support structured
+    /// outputs. Defaults to the name of the target type.
+    #[builder(default, setter(strip_option))]
+    pub schema_name: Option<String>,
+
+    /// A description of the object to generate.
+    #[builder(default, setter(strip_option))]
+    pub schema_description: Option<String>,
+
+    /// The sampling settings of the call and the policy for retrying it.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub settings: CallSettings,
+}
+
+impl GenerateObjectCallOptions {
+    /// Creates a new builder for `GenerateObjectCallOptions`.
+    pub fn builder() -> GenerateObjectCallOptionsBuilder {
+        GenerateObjectCallOptionsBuilder::default()
+    }
+
+    /// Returns the language model call options for this call, with the prompt
+    /// appended to the conversation.
+    pub(crate) fn call_options(&self) -> LanguageModelCallOptions {
+        call_options(&self.system, &self.prompt, &self.messages, &self.settings)
+    }
+}
+
+impl GenerateObjectCallOptionsBuilder {
+    fn validate(&self) -> Result<(), Error> {
+        validate_prompt(&self.prompt, &self.messages)
+    }
+}
+
+/// Response from a `generate_object` call.
+#[derive(Debug)]
+pub struct GenerateObjectResponse<T> {
+    /// The generated object.
+    pub object: T,
+
+    /// The reason the model stopped generating.
+    pub finish_reason: FinishReason,
+
+    /// The token usage of the request, if reported by the provider.
+    pub usage: Option<Usage>,
+}
+
+impl<T> GenerateObjectResponse<T> {
+    /// Creates a new response with the generated object.
+    pub fn new(object: T) -> Self {
+        Self {
+            object,
+            finish_reason: FinishReason::Stop,
+            usage: None,
+        }
     }
 }
 
+/// Options for a `stream_object` call.
+///
+/// Either `prompt` or `messages` must be set. When both are given, the
+/// prompt is appended to the conversation as a final user message.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into),
+    build_fn(error = "Error", validate = "Self::validate")
+)]
+pub struct StreamObjectCallOptions {
+    /// The system prompt to guide the model's behavior.
+    #[builder(default, setter(strip_option))]
+    pub system: Option<String>,
+
+    /// The prompt to generate the object from.
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+
+    /// The conversation history to generate the object from.
+    #[builder(default)]
+    pub messages: Vec<Message>,
+
+    /// The name of the schema, used by providers that support structured
+    /// outputs. Defaults to the name of the target type.
+    #[builder(default, setter(strip_option))]
+    pub schema_name: Option<String>,
+
+    /// A description of the object to generate.
+    #[builder(default, setter(strip_option))]
+    pub schema_description: Option<String>,
+
+    /// The sampling settings of the call and the policy for retrying it.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub settings: CallSettings,
+}
+
+impl StreamObjectCallOptions {
+    /// Creates a new builder for `StreamObjectCallOptions`.
+    pub fn builder() -> StreamObjectCallOptionsBuilder {
+        StreamObjectCallOptionsBuilder::default()
+    }
+}
+
+impl StreamObjectCallOptionsBuilder {
+    fn validate(&self) -> Result<(), Error> {
+        validate_prompt(&self.prompt, &self.messages)
+    }
+}
+
+impl From<StreamObjectCallOptions> for GenerateObjectCallOptions {
+    fn from(options: StreamObjectCallOptions) -> Self {
+        Self {
+            system: options.system,
+            prompt: options.prompt,
+            messages: options.messages,
+            schema_name: options.schema_name,
+            schema_description: options.schema_description,
+            settings: options.settings,
+        }
+    }
+}
+
+/// A chunk emitted while streaming an object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ObjectStreamChunk<T> {
+    /// The object generated so far. Emitted whenever it changes.
+    Partial(serde_json::Value),
+
+    /// The final object, validated against the target type.
+    Object(T),
+
+    /// An error that occurred while streaming or validating the object.
+    Error(String),
+}
+
+/// Response from a `stream_object` call.
+pub struct StreamObjectResponse<T> {
+    /// The stream of partial objects, ending with the final object.
+    pub stream: BoxStream<'static, ObjectStreamChunk<T>>,
+}
+
+impl<T> StreamObjectResponse<T> {
+    /// Creates a new response wrapping the given stream.
+    pub fn new(stream: BoxStream<'static, ObjectStreamChunk<T>>) -> Self {
+        Self { stream }
+    }
+}
+
+impl<T> std::fmt::Debug for StreamObjectResponse<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamObjectResponse")
+            .finish_non_exhaustive()
+    }
+}
+
+/// The format a language model should respond in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Free-form text.
+    Text,
+
+    /// A JSON value, optionally constrained by a JSON Schema.
+    Json {
+        /// The JSON Schema the response must match.
+        schema: Option<serde_json::Value>,
+
+        /// The name of the schema.
+        name: Option<String>,
+
+        /// A description of the expected response.
+        description: Option<String>,
+    },
+}
+
 /// Options for a language model request.
 #[derive(Debug, Clone, Serialize, Deserialize, Builder)]
 #[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
 pub struct LanguageModelCallOptions {
-    /// The prompt to generate text from.
-    pub prompt: String,
+    /// The system prompt to guide the model's behavior.
+    #[builder(default, setter(strip_option))]
+    pub system: Option<String>,
+
+    /// The conversation to generate a response for.
+    pub messages: Vec<Message>,
+
+    /// The definitions of the tools the model may call.
+    #[builder(default)]
+    pub tools: Vec<ToolDefinition>,
+
+    /// The format the model should respond in. Defaults to the provider's
+    /// default, which is usually text.
+    #[builder(default, setter(strip_option))]
+    pub response_format: Option<ResponseFormat>,
+
+    /// The sampling settings of the call and the policy for retrying it.
+    #[serde(flatten)]
+    #[builder(default)]
+    pub settings: CallSettings,
 }
 
 impl LanguageModelCallOptions {
@@ -57,6 +872,15 @@ pub struct LanguageModelResponse {
 
     /// The model that generated the response.
     pub model: Option<String>,
+
+    /// The tool calls requested by the model.
+    pub tool_calls: Vec<ToolCall>,
+
+    /// The reason the model stopped generating.
+    pub finish_reason: FinishReason,
+
+    /// The token usage of the request, if reported by the provider.
+    pub usage: Option<Usage>,
 }
 
 impl LanguageModelResponse {
@@ -65,6 +889,809 @@ impl LanguageModelResponse {
         Self {
             text: text.into(),
             model: None,
+            tool_calls: Vec::new(),
+            finish_reason: FinishReason::Stop,
+            usage: None,
         }
     }
 }
+
+/// The definition of a tool as sent to a language model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolDefinition {
+    /// The name of the tool.
+    pub name: String,
+
+    /// A description of what the tool does.
+    pub description: String,
+
+    /// The JSON Schema of the tool's parameters.
+    pub parameters: serde_json::Value,
+}
+
+/// The result of executing a tool call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolResult {
+    /// The id of the tool call that produced this result.
+    pub tool_call_id: String,
+
+    /// The name of the tool that was called.
+    pub name: String,
+
+    /// The value returned by the tool, or the error message when the tool
+    /// is unknown or failed.
+    pub result: serde_json::Value,
+
+    /// Whether the tool is unknown or failed to execute.
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+/// A call to a tool requested by a language model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// The provider-assigned id of the call.
+    pub id: String,
+
+    /// The name of the tool to call.
+    pub name: String,
+
+    /// The arguments to call the tool with. Arguments that the model did not
+    /// encode as valid JSON are kept as a string of the raw text.
+    pub arguments: serde_json::Value,
+}
+
+/// Token usage of a language model request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    /// The number of tokens in the prompt.
+    pub prompt_tokens: u32,
+
+    /// The number of tokens in the generated completion.
+    pub completion_tokens: u32,
+
+    /// The total number of tokens used.
+    pub total_tokens: u32,
+
+    /// The number of prompt tokens that were read from the provider's cache.
+    pub cached_tokens: Option<u32>,
+
+    /// The number of completion tokens spent on reasoning.
+    pub reasoning_tokens: Option<u32>,
+}
+
+impl std::ops::Add for Usage {
+    type Output = Usage;
+
+    fn add(self, other: Usage) -> Usage {
+        let add = |a: Option<u32>, b: Option<u32>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0).saturating_add(b.unwrap_or(0))),
+        };
+        Usage {
+            prompt_tokens: self.prompt_tokens.saturating_add(other.prompt_tokens),
+            completion_tokens: self
+                .completion_tokens
+                .saturating_add(other.completion_tokens),
+            total_tokens: self.total_tokens.saturating_add(other.total_tokens),
+            cached_tokens: add(self.cached_tokens, other.cached_tokens),
+            reasoning_tokens: add(self.reasoning_tokens, other.reasoning_tokens),
+        }
+    }
+}
+
+/// The reason a language model stopped generating.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishReason {
+    /// The model reached a natural stopping point or a stop sequence.
+    #[default]
+    Stop,
+
+    /// The maximum number of tokens was reached.
+    Length,
+
+    /// The model requested one or more tool calls.
+    ToolCalls,
+
+    /// The output was withheld by the provider's content filter.
+    ContentFilter,
+
+    /// The generation failed.
+    Error,
+
+    /// A provider-specific reason that has no normalized equivalent.
+    Other(String),
+}
+
+/// A chunk emitted while streaming a response from a language model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum LanguageModelStreamChunk {
+    /// A piece of generated text.
+    TextDelta(String),
+
+    /// A complete tool call requested by the model.
+    ToolCall(ToolCall),
+
+    /// The model finished generating.
+    Finish {
+        /// The reason the model stopped generating.
+        finish_reason: FinishReason,
+    },
+
+    /// Token usage of the request.
+    Usage(Usage),
+
+    /// An error that occurred while streaming.
+    Error(String),
+}
+
+/// A stream of chunks produced by a language model.
+pub type LanguageModelStream = BoxStream<'static, LanguageModelStreamChunk>;
+
+/// A vector representation of a value, produced by an embedding model.
+pub type Embedding = Vec<f32>;
+
+/// Options for an `embed` call.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct EmbedCallOptions {
+    /// The value to embed.
+    pub value: String,
+}
+
+impl EmbedCallOptions {
+    /// Creates a new builder for `EmbedCallOptions`.
+    pub fn builder() -> EmbedCallOptionsBuilder {
+        EmbedCallOptionsBuilder::default()
+    }
+}
+
+/// Response from an `embed` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedResponse {
+    /// The embedding of the value.
+    pub embedding: Embedding,
+
+    /// The token usage of the request, if reported by the provider.
+    pub usage: Option<EmbeddingUsage>,
+}
+
+/// Options for an `embed_many` call.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct EmbedManyCallOptions {
+    /// The values to embed.
+    pub values: Vec<String>,
+
+    /// The maximum number of requests sent to the provider at the same time.
+    /// Defaults to sending all requests at once when the model supports
+    /// parallel calls.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_parallel_calls: Option<usize>,
+}
+
+impl EmbedManyCallOptions {
+    /// Creates a new builder for `EmbedManyCallOptions`.
+    pub fn builder() -> EmbedManyCallOptionsBuilder {
+        EmbedManyCallOptionsBuilder::default()
+    }
+}
+
+/// Response from an `embed_many` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedManyResponse {
+    /// The embeddings of the values, in the order of the values.
+    pub embeddings: Vec<Embedding>,
+
+    /// The token usage of all requests, if reported by the provider.
+    pub usage: Option<EmbeddingUsage>,
+}
+
+/// Options for an embedding model request.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct EmbeddingModelCallOptions {
+    /// The values to embed. There are never more of them than the model's
+    /// maximum number of embeddings per call.
+    pub values: Vec<String>,
+}
+
+impl EmbeddingModelCallOptions {
+    /// Creates a new builder for `EmbeddingModelCallOptions`.
+    pub fn builder() -> EmbeddingModelCallOptionsBuilder {
+        EmbeddingModelCallOptionsBuilder::default()
+    }
+}
+
+/// Response from an embedding model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingModelResponse {
+    /// The embeddings of the values, in the order of the values.
+    pub embeddings: Vec<Embedding>,
+
+    /// The token usage of the request, if reported by the provider.
+    pub usage: Option<EmbeddingUsage>,
+}
+
+/// Token usage of an embedding model request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingUsage {
+    /// The number of tokens in the embedded values.
+    pub tokens: u32,
+}
+
+impl std::ops::Add for EmbeddingUsage {
+    type Output = EmbeddingUsage;
+
+    fn add(self, other: EmbeddingUsage) -> EmbeddingUsage {
+        EmbeddingUsage {
+            tokens: self.tokens.saturating_add(other.tokens),
+        }
+    }
+}
+
+/// Options for a `generate_image` call.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct GenerateImageCallOptions {
+    /// A description of the images to generate.
+    pub prompt: String,
+
+    /// The number of images to generate.
+    #[builder(default = "1", setter(into = false))]
+    pub n: u32,
+
+    /// The size of the images as `{width}x{height}`, e.g. `"1024x1024"`.
+    /// Uses the provider's default when unset.
+    #[builder(default, setter(strip_option))]
+    pub size: Option<String>,
+
+    /// The quality of the images, e.g. `"standard"` or `"hd"`. The supported
+    /// values depend on the model.
+    #[builder(default, setter(strip_option))]
+    pub quality: Option<String>,
+
+    /// The style of the images, e.g. `"vivid"` or `"natural"`. The supported
+    /// values depend on the model.
+    #[builder(default, setter(strip_option))]
+    pub style: Option<String>,
+
+    /// Whether the images are returned as bytes or as URLs. Uses the
+    /// provider's default when unset.
+    #[builder(default, setter(strip_option))]
+    pub output: Option<ImageOutput>,
+
+    /// The maximum number of requests sent to the provider at the same time.
+    /// Defaults to sending all requests at once.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_parallel_calls: Option<usize>,
+}
+
+impl GenerateImageCallOptions {
+    /// Creates a new builder for `GenerateImageCallOptions`.
+    pub fn builder() -> GenerateImageCallOptionsBuilder {
+        GenerateImageCallOptionsBuilder::default()
+    }
+
+    /// Returns the image model call options for a call generating `n` images.
+    pub(crate) fn call_options(&self, n: u32) -> ImageModelCallOptions {
+        ImageModelCallOptions {
+            prompt: self.prompt.clone(),
+            n,
+            size: self.size.clone(),
+            quality: self.quality.clone(),
+            style: self.style.clone(),
+            output: self.output,
+        }
+    }
+}
+
+/// Response from a `generate_image` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerateImageResponse {
+    /// The generated images.
+    pub images: Vec<GeneratedImage>,
+}
+
+/// How generated images are returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageOutput {
+    /// The encoded image files.
+    Bytes,
+
+    /// URLs the images can be downloaded from, usually for a limited time.
+    Url,
+}
+
+/// An image generated by an image model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedImage {
+    /// The image file or the URL it can be downloaded from.
+    pub data: ImageData,
+
+    /// The prompt the image was generated from, if the provider revised the
+    /// given prompt.
+    pub revised_prompt: Option<String>,
+}
+
+/// The content of a generated image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ImageData {
+    /// The encoded image file, e.g. a PNG.
+    Bytes(Vec<u8>),
+
+    /// A URL the image can be downloaded from.
+    Url(String),
+}
+
+/// Options for an image model request.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct ImageModelCallOptions {
+    /// A description of the images to generate.
+    pub prompt: String,
+
+    /// The number of images to generate. Never more than the model's maximum
+    /// number of images per call.
+    #[builder(default = "1", setter(into = false))]
+    pub n: u32,
+
+    /// The size of the images as `{width}x{height}`.
+    #[builder(default, setter(strip_option))]
+    pub size: Option<String>,
+
+    /// The quality of the images.
+    #[builder(default, setter(strip_option))]
+    pub quality: Option<String>,
+
+    /// The style of the images.
+    #[builder(default, setter(strip_option))]
+    pub style: Option<String>,
+
+    /// Whether the images are returned as bytes or as URLs.
+    #[builder(default, setter(strip_option))]
+    pub output: Option<ImageOutput>,
+}
+
+impl ImageModelCallOptions {
+    /// Creates a new builder for `ImageModelCallOptions`.
+    pub fn builder() -> ImageModelCallOptionsBuilder {
+        ImageModelCallOptionsBuilder::default()
+    }
+}
+
+/// Response from an image model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageModelResponse {
+    /// The generated images.
+    pub images: Vec<GeneratedImage>,
+}
+
+/// Options for a `generate_speech` or `stream_speech` call.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct GenerateSpeechCallOptions {
+    /// The text to speak.
+    pub text: String,
+
+    /// The voice to speak with, e.g. `"alloy"`. Uses the model's default
+    /// voice when unset.
+    #[builder(default, setter(strip_option))]
+    pub voice: Option<String>,
+
+    /// The format of the audio. Uses the provider's default when unset.
+    #[builder(default, setter(strip_option))]
+    pub format: Option<AudioFormat>,
+
+    /// The speed of the speech, where `1.0` is the normal speed.
+    #[builder(default, setter(into = false, strip_option))]
+    pub speed: Option<f32>,
+
+    /// Instructions on how to speak, such as the tone or accent. Only
+    /// supported by some models.
+    #[builder(default, setter(strip_option))]
+    pub instructions: Option<String>,
+}
+
+impl GenerateSpeechCallOptions {
+    /// Creates a new builder for `GenerateSpeechCallOptions`.
+    pub fn builder() -> GenerateSpeechCallOptionsBuilder {
+        GenerateSpeechCallOptionsBuilder::default()
+    }
+
+    /// Returns the speech model call options for this call.
+    pub(crate) fn call_options(self) -> SpeechModelCallOptions {
+        SpeechModelCallOptions {
+            text: self.text,
+            voice: self.voice,
+            format: self.format,
+            speed: self.speed,
+            instructions: self.instructions,
+        }
+    }
+}
+
+/// Response from a `generate_speech` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerateSpeechResponse {
+    /// The encoded audio.
+    pub audio: Vec<u8>,
+
+    /// The media type of the audio, e.g. `"audio/mpeg"`.
+    pub media_type: String,
+}
+
+/// Response from a `stream_speech` call.
+pub struct StreamSpeechResponse {
+    /// The stream of audio bytes, in the order they are played.
+    pub stream: AudioStream,
+
+    /// The media type of the audio, e.g. `"audio/mpeg"`.
+    pub media_type: String,
+}
+
+impl std::fmt::Debug for StreamSpeechResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamSpeechResponse")
+            .field("media_type", &self.media_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The encoding of audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    /// MP3.
+    Mp3,
+
+    /// Opus in an Ogg container.
+    Opus,
+
+    /// AAC.
+    Aac,
+
+    /// FLAC.
+    Flac,
+
+    /// WAV.
+    Wav,
+
+    /// Raw 16-bit little-endian PCM samples, without a header.
+    Pcm,
+}
+
+impl AudioFormat {
+    /// Returns the media type of audio in this format.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "audio/mpeg",
+            AudioFormat::Opus => "audio/ogg",
+            AudioFormat::Aac => "audio/aac",
+            AudioFormat::Flac => "audio/flac",
+            AudioFormat::Wav => "audio/wav",
+            AudioFormat::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// Options for a speech model request.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct SpeechModelCallOptions {
+    /// The text to speak.
+    pub text: String,
+
+    /// The voice to speak with.
+    #[builder(default, setter(strip_option))]
+    pub voice: Option<String>,
+
+    /// The format of the audio.
+    #[builder(default, setter(strip_option))]
+    pub format: Option<AudioFormat>,
+
+    /// The speed of the speech, where `1.0` is the normal speed.
+    #[builder(default, setter(into = false, strip_option))]
+    pub speed: Option<f32>,
+
+    /// Instructions on how to speak.
+    #[builder(default, setter(strip_option))]
+    pub instructions: Option<String>,
+}
+
+impl SpeechModelCallOptions {
+    /// Creates a new builder for `SpeechModelCallOptions`.
+    pub fn builder() -> SpeechModelCallOptionsBuilder {
+        SpeechModelCallOptionsBuilder::default()
+    }
+}
+
+/// Response from a speech model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeechModelResponse {
+    /// The encoded audio.
+    pub audio: Vec<u8>,
+
+    /// The media type of the audio.
+    pub media_type: String,
+}
+
+/// A stream of audio bytes produced by a speech model. Failures that occur
+/// after the stream has started are reported as `Err` items.
+pub type AudioStream = BoxStream<'static, crate::error::Result<Vec<u8>>>;
+
+/// Audio to transcribe, either in memory or in a file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum AudioInput {
+    /// The encoded audio file. Its media type is detected from the content.
+    Bytes(Vec<u8>),
+
+    /// The path of an audio file. Its media type is looked up from the
+    /// extension, falling back to detection from the content.
+    Path(PathBuf),
+}
+
+impl From<Vec<u8>> for AudioInput {
+    fn from(bytes: Vec<u8>) -> Self {
+        AudioInput::Bytes(bytes)
+    }
+}
+
+impl From<PathBuf> for AudioInput {
+    fn from(path: PathBuf) -> Self {
+        AudioInput::Path(path)
+    }
+}
+
+impl From<&Path> for AudioInput {
+    fn from(path: &Path) -> Self {
+        AudioInput::Path(path.to_path_buf())
+    }
+}
+
+/// Options for a `transcribe` call.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct TranscribeCallOptions {
+    /// The audio to transcribe.
+    pub audio: AudioInput,
+
+    /// The language of the audio as an ISO-639-1 code, e.g. `"en"`. Providing
+    /// it improves accuracy and latency. Detected by the model when unset.
+    #[builder(default, setter(strip_option))]
+    pub language: Option<String>,
+
+    /// Text that guides the style of the transcript or continues a previous
+    /// audio segment.
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+}
+
+impl TranscribeCallOptions {
+    /// Creates a new builder for `TranscribeCallOptions`.
+    pub fn builder() -> TranscribeCallOptionsBuilder {
+        TranscribeCallOptionsBuilder::default()
+    }
+}
+
+/// Response from a `transcribe` call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscribeResponse {
+    /// The transcribed text.
+    pub text: String,
+
+    /// The language of the audio, if reported by the provider.
+    pub language: Option<String>,
+
+    /// The duration of the audio in seconds, if reported by the provider.
+    pub duration: Option<f32>,
+
+    /// The timestamped segments of the transcript, if reported by the
+    /// provider.
+    pub segments: Vec<TranscriptionSegment>,
+}
+
+/// A timestamped segment of a transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSegment {
+    /// The transcribed text of the segment.
+    pub text: String,
+
+    /// The start of the segment in seconds.
+    pub start: f32,
+
+    /// The end of the segment in seconds.
+    pub end: f32,
+}
+
+/// Options for a transcription model request.
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct TranscriptionModelCallOptions {
+    /// The encoded audio file.
+    pub audio: Vec<u8>,
+
+    /// The media type of the audio, e.g. `"audio/mpeg"`.
+    pub media_type: String,
+
+    /// The language of the audio as an ISO-639-1 code.
+    #[builder(default, setter(strip_option))]
+    pub language: Option<String>,
+
+    /// Text that guides the style of the transcript.
+    #[builder(default, setter(strip_option))]
+    pub prompt: Option<String>,
+}
+
+impl TranscriptionModelCallOptions {
+    /// Creates a new builder for `TranscriptionModelCallOptions`.
+    pub fn builder() -> TranscriptionModelCallOptionsBuilder {
+        TranscriptionModelCallOptionsBuilder::default()
+    }
+}
+
+/// Response from a transcription model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionModelResponse {
+    /// The transcribed text.
+    pub text: String,
+
+    /// The language of the audio.
+    pub language: Option<String>,
+
+    /// The duration of the audio in seconds.
+    pub duration: Option<f32>,
+
+    /// The timestamped segments of the transcript.
+    pub segments: Vec<TranscriptionSegment>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_requires_prompt_or_messages() {
+        let result = GenerateTextCallOptions::builder()
+            .system("be brief")
+            .build();
+        assert!(matches!(result, Err(Error::MissingField(_))));
+    }
+
+    #[test]
+    fn test_conversation_appends_prompt_to_messages() {
+        let options = GenerateTextCallOptions::builder()
+            .messages(vec![Message::user("hi"), Message::assistant("hello")])
+            .prompt("how are you?")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            options.call_options().messages,
+            vec![
+                Message::user("hi"),
+                Message::assistant("hello"),
+                Message::user("how are you?"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_call_options_carries_sampling_settings() {
+        let options = GenerateTextCallOptions::builder()
+            .system("be brief")
+            .prompt("hi")
+            .temperature(0.2)
+            .seed(7)
+            .build()
+            .unwrap();
+
+        let call_options = options.call_options();
+
+        assert_eq!(call_options.system.as_deref(), Some("be brief"));
+        assert_eq!(call_options.messages, vec![Message::user("hi")]);
+        assert_eq!(call_options.settings.temperature, Some(0.2));
+        assert_eq!(call_options.settings.seed, Some(7));
+        assert_eq!(call_options.settings.max_tokens, None);
+    }
+
+    #[test]
+    fn test_call_settings_are_flattened() {
+        let settings = CallSettings::builder()
+            .top_p(0.5)
+            .stop_sequences(vec!["END".to_string()])
+            .build()
+            .unwrap();
+        let options = StreamObjectCallOptions::builder()
+            .prompt("hi")
+            .settings(settings.clone())
+            .max_tokens(64)
+            .build()
+            .unwrap();
+
+        assert_eq!(options.settings.top_p, Some(0.5));
+        assert_eq!(options.settings.max_tokens, Some(64));
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["top_p"], serde_json::json!(0.5));
+        assert_eq!(json["stop_sequences"], serde_json::json!(["END"]));
+    }
+
+    #[test]
+    fn test_usage_adds_up() {
+        let a = Usage {
+            prompt_tokens: 1,
+            completion_tokens: 2,
+            total_tokens: 3,
+            cached_tokens: Some(1),
+            reasoning_tokens: None,
+        };
+        let b = Usage {
+            prompt_tokens: 4,
+            completion_tokens: 5,
+            total_tokens: 9,
+            cached_tokens: None,
+            reasoning_tokens: None,
+        };
+
+        let total = a + b;
+
+        assert_eq!(total.total_tokens, 12);
+        assert_eq!(total.cached_tokens, Some(1));
+        assert_eq!(total.reasoning_tokens, None);
+    }
+
+    #[test]
+    fn test_usage_saturates_instead_of_overflowing() {
+        let usage = Usage {
+            total_tokens: u32::MAX,
+            cached_tokens: Some(u32::MAX),
+            ..Default::default()
+        };
+
+        let total = usage + usage;
+
+        assert_eq!(total.total_tokens, u32::MAX);
+        assert_eq!(total.cached_tokens, Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_message_serializes_with_role_tag() {
+        let json = serde_json::to_value(Message::tool("call_1", "42")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"role": "tool", "tool_call_id": "call_1", "content": "42"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_content_part_from_path_detects_kind() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("chart");
+        std::fs::write(&image, b"\x89PNG\r\n\x1a\n").unwrap();
+        let document = dir.path().join("invoice.pdf");
+        std::fs::write(&document, b"%PDF-1.7").unwrap();
+
+        assert_eq!(
+            ContentPart::from_path(&image).await.unwrap(),
+            ContentPart::image(b"\x89PNG\r\n\x1a\n".to_vec(), "image/png")
+        );
+        assert_eq!(
+            ContentPart::from_path(&document).await.unwrap(),
+            ContentPart::File {
+                data: b"%PDF-1.7".to_vec(),
+                media_type: "application/pdf".to_string(),
+                filename: Some("invoice.pdf".to_string()),
+            }
+        );
+        assert!(
+            ContentPart::from_path(dir.path().join("missing.png"))
+                .await
+                .is_err()
+        );
+    }
+}
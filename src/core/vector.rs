@@ -0,0 +1,149 @@
+//! Provides utilities for comparing and searching embeddings.
+//!
+//! This module contains the vector math that consumers of embeddings
+//! commonly need: dot products, cosine similarity, normalization and a
+//! brute-force nearest-neighbour search over embeddings held in memory.
+
+use crate::core::types::Embedding;
+
+/// An embedding found by `top_k`, with its similarity to the query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor {
+    /// The index of the embedding in the searched slice.
+    pub index: usize,
+
+    /// The cosine similarity of the embedding to the query.
+    pub similarity: f32,
+}
+
+/// Returns the dot product of two vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors have different lengths.
+pub fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "vectors must have the same length");
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Returns the Euclidean length of a vector.
+pub fn magnitude(vector: &[f32]) -> f32 {
+    vector.iter().map(|value| value * value).sum::<f32>().sqrt()
+}
+
+/// Returns the cosine similarity of two vectors, which ranges from `-1.0`
+/// for opposite directions to `1.0` for the same direction. Returns `0.0`
+/// when either vector has no length.
+///
+/// # Panics
+///
+/// Panics if the vectors have different lengths.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    similarity(a, magnitude(a), b)
+}
+
+/// Returns the cosine similarity of two vectors, given the magnitude of the
+/// first one, so that it can be computed once when comparing many vectors.
+fn similarity(a: &[f32], a_magnitude: f32, b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "vectors must have the same length");
+    let magnitudes = a_magnitude * magnitude(b);
+    if magnitudes == 0.0 {
+        return 0.0;
+    }
+    dot_product(a, b) / magnitudes
+}
+
+/// Scales a vector in place to a length of `1.0`. Vectors without length are
+/// left unchanged.
+///
+/// The dot product of normalized vectors equals their cosine similarity,
+/// which makes it cheaper to compare them.
+pub fn normalize(vector: &mut [f32]) {
+    let magnitude = magnitude(vector);
+    if magnitude == 0.0 {
+        return;
+    }
+    for value in vector {
+        *value /= magnitude;
+    }
+}
+
+/// Returns a copy of a vector scaled to a length of `1.0`.
+pub fn normalized(vector: &[f32]) -> Embedding {
+    let mut vector = vector.to_vec();
+    normalize(&mut vector);
+    vector
+}
+
+/// Finds the `k` embeddings most similar to the query by cosine similarity,
+/// ordered from the most to the least similar.
+///
+/// # Panics
+///
+/// Panics if an embedding has a different length than the query.
+pub fn top_k<E: AsRef<[f32]>>(query: &[f32], embeddings: &[E], k: usize) -> Vec<Neighbor> {
+    let query_magnitude = magnitude(query);
+    let mut neighbors: Vec<Neighbor> = embeddings
+        .iter()
+        .enumerate()
+        .map(|(index, embedding)| Neighbor {
+            index,
+            similarity: similarity(query, query_magnitude, embedding.as_ref()),
+        })
+        .collect();
+
+    neighbors.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    neighbors.truncate(k);
+    neighbors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]), 1.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 3.0]), 0.0);
+        assert!((cosine_similarity(&[1.0, 1.0], &[-1.0, -1.0]) + 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_cosine_similarity_rejects_different_lengths() {
+        cosine_similarity(&[0.0], &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_dot_product_rejects_different_lengths() {
+        dot_product(&[1.0], &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut vector = vec![3.0, 4.0];
+        normalize(&mut vector);
+        assert_eq!(vector, vec![0.6, 0.8]);
+
+        assert_eq!(normalized(&[0.0, 0.0]), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_top_k_orders_by_similarity() {
+        let embeddings: Vec<Embedding> = vec![
+            vec![0.0, 1.0],
+            vec![1.0, 0.1],
+            vec![-1.0, 0.0],
+            vec![1.0, 1.0],
+        ];
+
+        let neighbors = top_k(&[1.0, 0.0], &embeddings, 2);
+
+        let indices: Vec<_> = neighbors.iter().map(|n| n.index).collect();
+        assert_eq!(indices, [1, 3]);
+        assert!(neighbors[0].similarity > neighbors[1].similarity);
+        assert_eq!(top_k(&[1.0, 0.0], &embeddings, 10).len(), 4);
+    }
+}
@@ -14,7 +14,11 @@
 //! }
 //! ```
 
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
 use derive_builder::UninitializedFieldError;
+use reqwest::header::HeaderMap;
 
 /// A specialized `Result` type for SDK operations.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -26,19 +30,163 @@ pub enum Error {
     #[error("A required field is missing: {0}")]
     MissingField(String),
 
-    /// An error returned from the API.
-    #[error("API error: {0}")]
-    ApiError(String),
+    /// The API rejected the credentials, or they lack the permission for the
+    /// request.
+    #[error("Authentication error: {0}")]
+    AuthenticationError(ApiErrorDetails),
+
+    /// The API rate limited the request.
+    #[error("Rate limit error: {details}")]
+    RateLimitError {
+        /// The details of the error.
+        details: ApiErrorDetails,
+        /// How long the API asked to wait before retrying, if it said.
+        retry_after: Option<Duration>,
+    },
+
+    /// The prompt and the requested output exceed the context window of the
+    /// model.
+    #[error("Context length error: {0}")]
+    ContextLengthError(ApiErrorDetails),
+
+    /// The provider's content filter rejected the request.
+    #[error("Content filter error: {0}")]
+    ContentFilterError(ApiErrorDetails),
+
+    /// The API rejected the request as invalid, e.g. because of an unknown
+    /// model or a malformed parameter.
+    #[error("Invalid request error: {0}")]
+    InvalidRequestError(ApiErrorDetails),
+
+    /// The request contains content that the SDK cannot send to the provider
+    /// yet, e.g. an image part for a provider whose image support is not
+    /// implemented.
+    #[error("Unsupported content error: {0}")]
+    UnsupportedContentError(String),
+
+    /// The API failed to handle the request, or is overloaded.
+    #[error("Server error: {0}")]
+    ServerError(ApiErrorDetails),
+
+    /// The request timed out.
+    #[error("Timeout error: {0}")]
+    TimeoutError(String),
+
+    /// The response of the API could not be parsed.
+    #[error("Parse error: {0}")]
+    ParseError(String),
 
-    /// An error from the underlying `reqwest` client.
+    /// An error from the underlying `reqwest` client, such as a failure to
+    /// connect.
     #[error("HTTP request error: {0}")]
-    ReqwestError(#[from] reqwest::Error),
+    ReqwestError(reqwest::Error),
+
+    /// An error serializing or deserializing JSON.
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
 
     /// A catch-all for other miscellaneous errors.
     #[error("AI SDK error: {0}")]
     Other(String),
 }
 
+impl Error {
+    /// Creates an error from a response with an unsuccessful status code,
+    /// parsing the provider's error JSON from its body.
+    pub async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(err) => return err.into(),
+        };
+
+        let mut details = ApiErrorDetails::parse(Some(status), &body);
+        details.request_id = REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|name| headers.get(*name)?.to_str().ok())
+            .map(str::to_string);
+
+        Self::from_api_error(details, retry_after(&headers, SystemTime::now()))
+    }
+
+    /// Creates an error of the kind matching the status code and the
+    /// provider's error code and message.
+    pub fn from_api_error(details: ApiErrorDetails, retry_after: Option<Duration>) -> Self {
+        let code = details.code.as_deref().unwrap_or_default().to_lowercase();
+        let message = details.message.to_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|pattern| code.contains(pattern));
+
+        if matches(&["context_length", "context_window"])
+            || CONTEXT_LENGTH_MESSAGES
+                .iter()
+                .any(|pattern| message.contains(pattern))
+        {
+            return Error::ContextLengthError(details);
+        }
+        if matches(&["content_filter", "content_policy", "safety"]) {
+            return Error::ContentFilterError(details);
+        }
+
+        match details.status {
+            Some(401 | 403) => Error::AuthenticationError(details),
+            Some(408) => Error::TimeoutError(details.to_string()),
+            Some(429) => Error::RateLimitError {
+                details,
+                retry_after,
+            },
+            Some(400..=499) => Error::InvalidRequestError(details),
+            Some(_) => Error::ServerError(details),
+            // Errors reported in the middle of a stream carry no status code.
+            None if matches(&["rate_limit", "ratelimit", "resource_exhausted", "throttl"]) => {
+                Error::RateLimitError {
+                    details,
+                    retry_after,
+                }
+            }
+            None if matches(&["auth", "permission", "access_denied", "accessdenied"]) => {
+                Error::AuthenticationError(details)
+            }
+            None if matches(&["invalid", "validation", "not_found"]) => {
+                Error::InvalidRequestError(details)
+            }
+            None => Error::ServerError(details),
+        }
+    }
+
+    /// Returns whether retrying the request that failed with this error may
+    /// succeed: rate limits, server errors, timeouts and connection failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RateLimitError { .. } | Error::ServerError(_) | Error::TimeoutError(_) => true,
+            Error::ReqwestError(err) => err.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Returns how long the API asked to wait before retrying, if it said.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimitError { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// Implements `From` for `reqwest::Error`, classifying timeouts and failures
+/// to decode the response body.
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Error::TimeoutError(err.to_string())
+        } else if err.is_decode() {
+            Error::ParseError(err.to_string())
+        } else {
+            Error::ReqwestError(err)
+        }
+    }
+}
+
 /// Implements `From` for `UninitializedFieldError` to convert it to `Error`.
 /// Mainly used for the `derive_builder` crate.
 impl From<UninitializedFieldError> for Error {
@@ -46,3 +194,249 @@ impl From<UninitializedFieldError> for Error {
         Error::MissingField(err.field_name().to_string())
     }
 }
+
+/// The details of an error reported by a provider's API.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiErrorDetails {
+    /// The HTTP status code, or `None` for errors reported in the middle of a
+    /// stream.
+    pub status: Option<u16>,
+
+    /// The provider's error code or type, e.g. `"rate_limit_exceeded"`.
+    pub code: Option<String>,
+
+    /// The error message, or the raw response body when it is not JSON.
+    pub message: String,
+
+    /// The ID of the request, for reference in support requests.
+    pub request_id: Option<String>,
+}
+
+impl ApiErrorDetails {
+    /// Parses the error JSON of a provider. The shapes used by the supported
+    /// providers are recognized, such as `{"error": {"message", "code"}}`,
+    /// `{"error": "message"}` and `{"message", "type"}`. Any other body is
+    /// used as the message as is.
+    pub fn parse(status: Option<u16>, body: &str) -> Self {
+        let value: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+        let string = |value: &serde_json::Value, keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| value.get(key)?.as_str())
+                .map(str::to_string)
+        };
+
+        let (message, code) = match value.get("error") {
+            Some(error) if error.is_object() => (
+                string(error, &["message"]),
+                string(error, &["code", "type", "status"]),
+            ),
+            Some(serde_json::Value::String(message)) => {
+                (Some(message.to_string()), string(&value, &["error_type"]))
+            }
+            _ => (
+                string(&value, &["message", "detail"]),
+                string(&value, &["code", "type"]),
+            ),
+        };
+
+        Self {
+            status,
+            code,
+            message: message.unwrap_or_else(|| body.to_string()),
+            request_id: None,
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        let context: Vec<String> = [
+            self.status.map(|status| format!("status {status}")),
+            self.code.as_ref().map(|code| format!("code {code}")),
+            self.request_id
+                .as_ref()
+                .map(|id| format!("request id {id}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !context.is_empty() {
+            write!(f, " ({})", context.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The headers the supported providers return the request ID in.
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "request-id", "x-amzn-requestid"];
+
+/// Phrases of the messages providers return when the context length is
+/// exceeded without a dedicated error code.
+const CONTEXT_LENGTH_MESSAGES: &[&str] = &[
+    "context length",
+    "context window",
+    "maximum context",
+    "prompt is too long",
+    "input is too long",
+    "too many tokens",
+];
+
+/// Parses how long to wait before retrying from the `retry-after-ms` or
+/// `retry-after` header. The latter holds either seconds or an HTTP date.
+fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let header = |name| headers.get(name)?.to_str().ok();
+
+    if let Some(millis) = header("retry-after-ms").and_then(|value| value.parse::<f64>().ok()) {
+        return Duration::try_from_secs_f64(millis / 1000.0).ok();
+    }
+    let value = header("retry-after")?;
+    match value.parse::<f64>() {
+        Ok(seconds) => Duration::try_from_secs_f64(seconds).ok(),
+        Err(_) => {
+            let date = httpdate::parse_http_date(value).ok()?;
+            Some(date.duration_since(now).unwrap_or_default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn error(status: Option<u16>, body: &str) -> Error {
+        Error::from_api_error(ApiErrorDetails::parse(status, body), None)
+    }
+
+    #[test]
+    fn test_parse_recognizes_provider_error_shapes() {
+        let openai = ApiErrorDetails::parse(
+            Some(400),
+            r#"{"error": {"message": "bad", "type": "invalid_request_error", "code": "invalid_value"}}"#,
+        );
+        assert_eq!(openai.message, "bad");
+        assert_eq!(openai.code.as_deref(), Some("invalid_value"));
+
+        let anthropic = ApiErrorDetails::parse(
+            Some(529),
+            r#"{"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}"#,
+        );
+        assert_eq!(anthropic.code.as_deref(), Some("overloaded_error"));
+
+        let tgi = ApiErrorDetails::parse(
+            Some(422),
+            r#"{"error": "Input validation error", "error_type": "validation"}"#,
+        );
+        assert_eq!(tgi.message, "Input validation error");
+        assert_eq!(tgi.code.as_deref(), Some("validation"));
+
+        let cohere = ApiErrorDetails::parse(Some(400), r#"{"message": "invalid model"}"#);
+        assert_eq!(cohere.message, "invalid model");
+
+        let plain = ApiErrorDetails::parse(Some(502), "Bad Gateway");
+        assert_eq!(plain.message, "Bad Gateway");
+        assert_eq!(plain.code, None);
+    }
+
+    #[test]
+    fn test_from_api_error_classifies_errors() {
+        assert!(matches!(
+            error(Some(401), r#"{"error": {"message": "Incorrect API key"}}"#),
+            Error::AuthenticationError(_)
+        ));
+        assert!(matches!(
+            error(Some(429), "{}"),
+            Error::RateLimitError { .. }
+        ));
+        assert!(matches!(
+            error(
+                Some(400),
+                r#"{"error": {"message": "too long", "code": "context_length_exceeded"}}"#
+            ),
+            Error::ContextLengthError(_)
+        ));
+        assert!(matches!(
+            error(
+                Some(400),
+                r#"{"type": "error", "error": {"type": "invalid_request_error", "message": "prompt is too long: 210000 tokens > 200000 maximum"}}"#
+            ),
+            Error::ContextLengthError(_)
+        ));
+        assert!(matches!(
+            error(
+                Some(400),
+                r#"{"error": {"message": "filtered", "code": "content_filter"}}"#
+            ),
+            Error::ContentFilterError(_)
+        ));
+        assert!(matches!(
+            error(Some(404), r#"{"error": "model 'llama9' not found"}"#),
+            Error::InvalidRequestError(_)
+        ));
+        assert!(matches!(error(Some(503), ""), Error::ServerError(_)));
+        assert!(matches!(error(Some(408), ""), Error::TimeoutError(_)));
+    }
+
+    #[test]
+    fn test_from_api_error_classifies_stream_errors_by_code() {
+        let throttled = ApiErrorDetails {
+            code: Some("throttlingException".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            Error::from_api_error(throttled, None),
+            Error::RateLimitError { .. }
+        ));
+        assert!(matches!(
+            error(None, r#"{"error": {"message": "boom"}}"#),
+            Error::ServerError(_)
+        ));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(error(Some(429), "").is_retryable());
+        assert!(error(Some(500), "").is_retryable());
+        assert!(Error::TimeoutError("timed out".to_string()).is_retryable());
+        assert!(!error(Some(401), "").is_retryable());
+        assert!(!error(Some(400), "").is_retryable());
+        assert!(!Error::Other("other".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_retry_after_parses_headers() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480);
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers, now), None);
+
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:30 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(30)));
+
+        headers.insert("retry-after", HeaderValue::from_static("20"));
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(20)));
+
+        headers.insert("retry-after-ms", HeaderValue::from_static("1500"));
+        assert_eq!(
+            retry_after(&headers, now),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn test_display_includes_context() {
+        let details = ApiErrorDetails {
+            status: Some(429),
+            code: Some("rate_limit_exceeded".to_string()),
+            message: "Slow down".to_string(),
+            request_id: Some("req_123".to_string()),
+        };
+        assert_eq!(
+            details.to_string(),
+            "Slow down (status 429, code rate_limit_exceeded, request id req_123)"
+        );
+    }
+}
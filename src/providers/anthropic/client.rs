@@ -0,0 +1,264 @@
+//! This module provides a client for interacting with the Anthropic Messages API.
+//! It handles the construction of requests, sending them to the API,
+//! and parsing the responses.
+
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::{ApiErrorDetails, Error, Result};
+use crate::providers::sse;
+
+/// A client for the Anthropic API.
+#[derive(Debug, Clone)]
+pub struct AnthropicClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    api_version: String,
+    retry_policy: RetryPolicy,
+}
+
+impl AnthropicClient {
+    /// Creates a new `AnthropicClient` for the API at the given base URL.
+    pub fn new(api_key: String, base_url: String, api_version: String) -> Self {
+        Self {
+            client: Client::new(),
+            api_key,
+            base_url,
+            api_version,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the Messages API and returns the complete response.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        request: &MessagesRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<MessagesResponse> {
+        let response = self.send(request, retry_policy).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a streaming request to the Messages API.
+    ///
+    /// The returned stream yields the parsed server-sent events until the
+    /// API closes the connection.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        request: &MessagesRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<StreamEvent>>> {
+        let response = self.send(request, retry_policy).await?;
+
+        let events = sse::data_stream(response).map(|data| {
+            let event = serde_json::from_str(&data?)?;
+            match event {
+                StreamEvent::Error { error } => Err(Error::from_api_error(
+                    ApiErrorDetails {
+                        code: Some(error.kind),
+                        message: error.message,
+                        ..Default::default()
+                    },
+                    None,
+                )),
+                event => Ok(event),
+            }
+        });
+
+        Ok(events.boxed())
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        request: &MessagesRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let url = format!("{}/messages", self.base_url);
+                let response = self
+                    .client
+                    .post(&url)
+                    .header("x-api-key", &self.api_key)
+                    .header("anthropic-version", &self.api_version)
+                    .json(request)
+                    .send()
+                    .await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// A request to the Messages API.
+#[derive(Debug, Serialize)]
+pub struct MessagesRequest {
+    pub model: String,
+    pub max_tokens: u32,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+/// A message in a Messages API request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Message {
+    pub role: String,
+    pub content: Vec<ContentBlock>,
+}
+
+/// A block of content in a message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: serde_json::Value,
+    },
+    ToolResult {
+        tool_use_id: String,
+        content: String,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        is_error: bool,
+    },
+    /// A block type that this client does not handle, such as thinking.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A tool that the model may use.
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    pub name: String,
+    pub description: String,
+    pub input_schema: serde_json::Value,
+}
+
+/// A response from the Messages API.
+#[derive(Debug, Deserialize)]
+pub struct MessagesResponse {
+    pub id: String,
+    pub model: String,
+    pub content: Vec<ContentBlock>,
+    pub stop_reason: Option<String>,
+    pub usage: Usage,
+}
+
+/// Token usage reported by the Anthropic API.
+#[derive(Debug, Default, Deserialize)]
+pub struct Usage {
+    #[serde(default)]
+    pub input_tokens: u32,
+    #[serde(default)]
+    pub output_tokens: u32,
+    pub cache_creation_input_tokens: Option<u32>,
+    pub cache_read_input_tokens: Option<u32>,
+}
+
+/// An event of a streamed Messages API response.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamEvent {
+    MessageStart {
+        message: StreamMessage,
+    },
+    ContentBlockStart {
+        index: u32,
+        content_block: ContentBlock,
+    },
+    ContentBlockDelta {
+        index: u32,
+        delta: ContentBlockDelta,
+    },
+    ContentBlockStop {
+        index: u32,
+    },
+    MessageDelta {
+        delta: MessageDelta,
+        usage: Usage,
+    },
+    MessageStop,
+    Ping,
+    Error {
+        error: ApiError,
+    },
+    /// An event type added to the API after this client was written, which
+    /// clients must ignore.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The message metadata sent at the start of a stream.
+#[derive(Debug, Deserialize)]
+pub struct StreamMessage {
+    pub id: String,
+    pub model: String,
+    pub usage: Usage,
+}
+
+/// An incremental update to a content block.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlockDelta {
+    TextDelta {
+        text: String,
+    },
+    InputJsonDelta {
+        partial_json: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// The top-level changes to a message at the end of a stream.
+#[derive(Debug, Deserialize)]
+pub struct MessageDelta {
+    pub stop_reason: Option<String>,
+}
+
+/// An error reported by the Anthropic API.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub message: String,
+}
@@ -0,0 +1,437 @@
+//! This module provides the Anthropic provider, which implements the `LanguageModel`
+//! and `Provider` traits for interacting with the Anthropic Messages API.
+
+pub mod client;
+pub mod settings;
+
+pub use settings::AnthropicProviderSettings;
+
+use self::client::{
+    AnthropicClient, ContentBlock, ContentBlockDelta, Message, MessagesRequest, StreamEvent, Tool,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ToolCall, Usage,
+        },
+    },
+    error::Result,
+    providers::user_text,
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// The Anthropic provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Anthropic {
+    #[serde(skip)]
+    client: AnthropicClient,
+    settings: AnthropicProviderSettings,
+}
+
+impl Anthropic {
+    /// Creates a new `Anthropic` provider with the given settings.
+    pub fn new(settings: AnthropicProviderSettings) -> Self {
+        let client = AnthropicClient::new(
+            settings.api_key.to_string(),
+            settings.base_url.to_string(),
+            settings.api_version.to_string(),
+        )
+        .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Builds a Messages API request from the provider-neutral call options.
+    fn messages_request(
+        &self,
+        options: LanguageModelCallOptions,
+        stream: bool,
+    ) -> Result<MessagesRequest> {
+        if options.settings.presence_penalty.is_some()
+            || options.settings.frequency_penalty.is_some()
+        {
+            log::warn!("Anthropic does not support penalties, ignoring them");
+        }
+        if options.settings.seed.is_some() {
+            log::warn!("Anthropic does not support `seed`, ignoring it");
+        }
+
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                name: tool.name,
+                description: tool.description,
+                input_schema: tool.parameters,
+            })
+            .collect::<Vec<_>>();
+        let (system, messages) = to_anthropic_messages(options.system, options.messages)?;
+
+        Ok(MessagesRequest {
+            model: self.model_name().to_string(),
+            max_tokens: options
+                .settings
+                .max_tokens
+                .unwrap_or(self.settings.max_tokens),
+            messages,
+            system,
+            tools: (!tools.is_empty()).then_some(tools),
+            temperature: options.settings.temperature,
+            top_p: options.settings.top_p,
+            top_k: options.settings.top_k,
+            stop_sequences: (!options.settings.stop_sequences.is_empty())
+                .then_some(options.settings.stop_sequences),
+            stream: stream.then_some(true),
+        })
+    }
+}
+
+impl Provider for Anthropic {}
+
+#[async_trait]
+impl LanguageModel for Anthropic {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.messages_request(options, false)?;
+
+        let response = self
+            .client
+            .generate_text(&request, retry_policy.as_ref())
+            .await?;
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for block in response.content {
+            match block {
+                ContentBlock::Text { text: block_text } => text.push_str(&block_text),
+                ContentBlock::ToolUse { id, name, input } => tool_calls.push(ToolCall {
+                    id,
+                    name,
+                    arguments: input,
+                }),
+                _ => {}
+            }
+        }
+
+        Ok(LanguageModelResponse {
+            text,
+            model: Some(response.model),
+            tool_calls,
+            finish_reason: to_finish_reason(response.stop_reason.as_deref()),
+            usage: Some(to_usage(&response.usage, 0)),
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.messages_request(options, true)?;
+
+        let events = self
+            .client
+            .stream_text(&request, retry_policy.as_ref())
+            .await?;
+
+        let mut state = StreamState::default();
+        Ok(events
+            .map(move |event| match event {
+                Ok(event) => state.process(event),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts an Anthropic stop reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("end_turn" | "stop_sequence") => FinishReason::Stop,
+        Some("max_tokens") => FinishReason::Length,
+        Some("tool_use") => FinishReason::ToolCalls,
+        Some("refusal") => FinishReason::ContentFilter,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts Anthropic token usage into normalized token usage. Anthropic
+/// reports input tokens at the start of a stream, so they can be passed in
+/// separately when the usage comes from the final event.
+fn to_usage(usage: &client::Usage, input_tokens: u32) -> Usage {
+    let prompt_tokens = usage
+        .input_tokens
+        .max(input_tokens)
+        .saturating_add(usage.cache_creation_input_tokens.unwrap_or(0))
+        .saturating_add(usage.cache_read_input_tokens.unwrap_or(0));
+    Usage {
+        prompt_tokens,
+        completion_tokens: usage.output_tokens,
+        total_tokens: prompt_tokens.saturating_add(usage.output_tokens),
+        cached_tokens: usage.cache_read_input_tokens,
+        reasoning_tokens: None,
+    }
+}
+
+/// A tool use block whose input is still being streamed.
+#[derive(Debug, Default)]
+struct PendingToolUse {
+    id: String,
+    name: String,
+    input: String,
+}
+
+/// Converts streamed Anthropic events into provider-neutral stream chunks,
+/// assembling tool use blocks into complete calls.
+#[derive(Debug, Default)]
+struct StreamState {
+    usage: client::Usage,
+    tool_uses: BTreeMap<u32, PendingToolUse>,
+}
+
+impl StreamState {
+    fn process(&mut self, event: StreamEvent) -> Vec<LanguageModelStreamChunk> {
+        match event {
+            StreamEvent::MessageStart { message } => {
+                self.usage = message.usage;
+                vec![]
+            }
+            StreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => match content_block {
+                ContentBlock::Text { text } if !text.is_empty() => {
+                    vec![LanguageModelStreamChunk::TextDelta(text)]
+                }
+                ContentBlock::ToolUse { id, name, .. } => {
+                    self.tool_uses.insert(
+                        index,
+                        PendingToolUse {
+                            id,
+                            name,
+                            input: String::new(),
+                        },
+                    );
+                    vec![]
+                }
+                _ => vec![],
+            },
+            StreamEvent::ContentBlockDelta { index, delta } => match delta {
+                ContentBlockDelta::TextDelta { text } => {
+                    vec![LanguageModelStreamChunk::TextDelta(text)]
+                }
+                ContentBlockDelta::InputJsonDelta { partial_json } => {
+                    if let Some(tool_use) = self.tool_uses.get_mut(&index) {
+                        tool_use.input.push_str(&partial_json);
+                    }
+                    vec![]
+                }
+                ContentBlockDelta::Unknown => vec![],
+            },
+            StreamEvent::ContentBlockStop { index } => match self.tool_uses.remove(&index) {
+                Some(tool_use) => {
+                    let input = if tool_use.input.trim().is_empty() {
+                        Ok(serde_json::Value::Object(Default::default()))
+                    } else {
+                        serde_json::from_str(&tool_use.input)
+                    };
+                    vec![match input {
+                        Ok(arguments) => LanguageModelStreamChunk::ToolCall(ToolCall {
+                            id: tool_use.id,
+                            name: tool_use.name,
+                            arguments,
+                        }),
+                        Err(err) => LanguageModelStreamChunk::Error(err.to_string()),
+                    }]
+                }
+                None => vec![],
+            },
+            StreamEvent::MessageDelta { delta, usage } => vec![
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: to_finish_reason(delta.stop_reason.as_deref()),
+                },
+                LanguageModelStreamChunk::Usage(to_usage(&usage, self.usage.input_tokens)),
+            ],
+            // Error events are turned into errors by the client before they
+            // reach the stream state.
+            StreamEvent::MessageStop
+            | StreamEvent::Ping
+            | StreamEvent::Error { .. }
+            | StreamEvent::Unknown => vec![],
+        }
+    }
+}
+
+/// Converts the provider-neutral conversation into Anthropic messages.
+///
+/// The Messages API only accepts a top-level system prompt, so system messages
+/// are appended to it. Tool results are sent as user messages, and
+/// consecutive messages with the same role are merged, since the API expects
+/// the roles to alternate.
+fn to_anthropic_messages(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<(Option<String>, Vec<Message>)> {
+    let mut system_parts: Vec<String> = system.into_iter().collect();
+    let mut result: Vec<Message> = Vec::new();
+
+    for message in messages {
+        let (role, content) = match message {
+            types::Message::System { content } => {
+                system_parts.push(content);
+                continue;
+            }
+            types::Message::User { content } => (
+                "user",
+                vec![ContentBlock::Text {
+                    text: user_text("Anthropic", content)?,
+                }],
+            ),
+            types::Message::Assistant {
+                content,
+                tool_calls,
+            } => {
+                let text = (!content.is_empty()).then_some(ContentBlock::Text { text: content });
+                let tool_uses = tool_calls.into_iter().map(|call| ContentBlock::ToolUse {
+                    id: call.id,
+                    name: call.name,
+                    input: call.arguments,
+                });
+                ("assistant", text.into_iter().chain(tool_uses).collect())
+            }
+            types::Message::Tool {
+                tool_call_id,
+                content,
+                is_error,
+            } => (
+                "user",
+                vec![ContentBlock::ToolResult {
+                    tool_use_id: tool_call_id,
+                    content,
+                    is_error,
+                }],
+            ),
+        };
+
+        match result.last_mut() {
+            Some(last) if last.role == role => last.content.extend(content),
+            _ => result.push(Message {
+                role: role.to_string(),
+                content,
+            }),
+        }
+    }
+
+    let system = (!system_parts.is_empty()).then(|| system_parts.join("\n\n"));
+    Ok((system, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_anthropic_messages_merges_roles_and_hoists_system() {
+        let (system, messages) = to_anthropic_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::system("use metric units"),
+                types::Message::user("weather in Paris and Rome?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "toolu_1".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: serde_json::json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("toolu_1", "21"),
+                types::Message::tool_error("toolu_2", "Unknown tool: get_time"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(system.as_deref(), Some("be brief\n\nuse metric units"));
+        let roles: Vec<_> = messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["user", "assistant", "user"]);
+        assert_eq!(
+            serde_json::to_value(&messages[2].content).unwrap(),
+            serde_json::json!([
+                {"type": "tool_result", "tool_use_id": "toolu_1", "content": "21"},
+                {
+                    "type": "tool_result",
+                    "tool_use_id": "toolu_2",
+                    "content": "Unknown tool: get_time",
+                    "is_error": true
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_stream_state_assembles_tool_use_and_usage() {
+        let events = [
+            r#"{"type":"message_start","message":{"id":"msg_1","model":"claude","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Checking"}}"#,
+            r#"{"type":"content_block_stop","index":0}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"get_weather","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"city\": "}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"\"Paris\"}"}}"#,
+            r#"{"type":"content_block_stop","index":1}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":20}}"#,
+            r#"{"type":"message_stop"}"#,
+        ];
+
+        let mut state = StreamState::default();
+        let chunks: Vec<_> = events
+            .iter()
+            .flat_map(|event| state.process(serde_json::from_str(event).unwrap()))
+            .collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                LanguageModelStreamChunk::TextDelta("Checking".to_string()),
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "toolu_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: serde_json::json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+                LanguageModelStreamChunk::Usage(Usage {
+                    prompt_tokens: 10,
+                    completion_tokens: 20,
+                    total_tokens: 30,
+                    cached_tokens: None,
+                    reasoning_tokens: None,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_state_ignores_unknown_events() {
+        let event = serde_json::from_str(
+            r#"{"type":"content_block_annotation","index":0,"annotation":{"kind":"new"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, StreamEvent::Unknown));
+
+        let mut state = StreamState::default();
+        assert_eq!(state.process(event), vec![]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Defines the settings for the Anthropic provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Anthropic provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct AnthropicProviderSettings {
+    /// The API key for the Anthropic API.
+    #[builder(default = "std::env::var(\"ANTHROPIC_API_KEY\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// The model to use for text generation.
+    #[builder(default = "\"claude-sonnet-4-5\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"anthropic\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the Anthropic API.
+    #[builder(default = "\"https://api.anthropic.com/v1\".to_string()")]
+    pub base_url: String,
+
+    /// The version of the Anthropic API to use.
+    #[builder(default = "\"2023-06-01\".to_string()")]
+    pub api_version: String,
+
+    /// The maximum number of tokens to generate. The Messages API requires
+    /// this to be set on every request.
+    #[builder(default = "1024")]
+    pub max_tokens: u32,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl AnthropicProviderSettings {
+    /// Creates a new builder for `AnthropicProviderSettings`.
+    pub fn builder() -> AnthropicProviderSettingsBuilder {
+        AnthropicProviderSettingsBuilder::default()
+    }
+}
@@ -0,0 +1,124 @@
+//! This module provides the Azure OpenAI provider, which implements the
+//! `LanguageModel` and `Provider` traits for models deployed to an Azure
+//! OpenAI resource.
+//!
+//! Azure serves the OpenAI chat completions API from deployment-specific URLs
+//! with its own authentication, so the provider reuses the OpenAI provider
+//! with settings adapted to that scheme.
+
+pub mod settings;
+
+pub use settings::AzureProviderSettings;
+
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{LanguageModelCallOptions, LanguageModelResponse, LanguageModelStream},
+    },
+    error::Result,
+    providers::openai::{OpenAI, OpenAIProviderSettings},
+};
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// The Azure OpenAI provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Azure {
+    #[serde(skip)]
+    openai: OpenAI,
+    settings: AzureProviderSettings,
+}
+
+impl Azure {
+    /// Creates a new `Azure` provider with the given settings.
+    pub fn new(settings: AzureProviderSettings) -> Self {
+        let openai = OpenAI::new(to_openai_settings(&settings));
+        Self { openai, settings }
+    }
+}
+
+impl Provider for Azure {}
+
+#[async_trait]
+impl LanguageModel for Azure {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.deployment_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        self.openai.supports_structured_output()
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        self.openai.generate(options).await
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        self.openai.stream(options).await
+    }
+}
+
+/// Converts the Azure settings into settings for the OpenAI provider, which
+/// point at the deployment and authenticate the way Azure expects.
+fn to_openai_settings(settings: &AzureProviderSettings) -> OpenAIProviderSettings {
+    let base_url = format!(
+        "{}/openai/deployments/{}",
+        settings.endpoint.trim_end_matches('/'),
+        settings.deployment_name
+    );
+    let builder = OpenAIProviderSettings::builder()
+        .base_url(base_url)
+        .query_param("api-version", &settings.api_version)
+        .model_name(&settings.deployment_name)
+        .provider_name(&settings.provider_name)
+        .max_tokens(settings.max_tokens)
+        .compatibility(settings.compatibility.clone())
+        .retry_policy(settings.retry_policy.clone());
+    let builder = match &settings.ad_token {
+        Some(token) => builder.api_key(token),
+        None => builder.api_key("").header("api-key", &settings.api_key),
+    };
+
+    builder
+        .build()
+        .expect("all OpenAI provider settings have defaults")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_openai_settings_uses_deployment_url_and_auth() {
+        let settings = AzureProviderSettings::builder()
+            .endpoint("https://my-resource.openai.azure.com/")
+            .deployment_name("gpt-4o-prod")
+            .api_key("secret")
+            .build()
+            .unwrap();
+
+        let openai = to_openai_settings(&settings);
+
+        assert_eq!(
+            openai.base_url,
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-4o-prod"
+        );
+        assert_eq!(openai.query_params["api-version"], "2024-10-21");
+        assert_eq!(openai.api_key, "");
+        assert_eq!(openai.headers["api-key"], "secret");
+
+        let settings = AzureProviderSettings {
+            ad_token: Some("token".to_string()),
+            ..settings
+        };
+        let openai = to_openai_settings(&settings);
+
+        assert_eq!(openai.api_key, "token");
+        assert!(openai.headers.is_empty());
+    }
+}
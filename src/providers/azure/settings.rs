@@ -0,0 +1,58 @@
+//! Defines the settings for the Azure OpenAI provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+use crate::providers::openai::Compatibility;
+
+/// Settings for the Azure OpenAI provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct AzureProviderSettings {
+    /// The endpoint of the Azure OpenAI resource, e.g.
+    /// `https://my-resource.openai.azure.com`.
+    #[builder(default = "std::env::var(\"AZURE_OPENAI_ENDPOINT\").unwrap_or_default()")]
+    pub endpoint: String,
+
+    /// The name of the model deployment to use.
+    pub deployment_name: String,
+
+    /// The version of the Azure OpenAI API.
+    #[builder(default = "\"2024-10-21\".to_string()")]
+    pub api_version: String,
+
+    /// The API key of the Azure OpenAI resource, sent in the `api-key` header.
+    #[builder(default = "std::env::var(\"AZURE_OPENAI_API_KEY\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// A Microsoft Entra ID (Azure AD) access token. When set, it is sent as
+    /// a bearer token instead of the API key.
+    #[builder(default, setter(strip_option))]
+    pub ad_token: Option<String>,
+
+    /// The name of the provider.
+    #[builder(default = "\"azure\".to_string()")]
+    pub provider_name: String,
+
+    /// The maximum number of tokens to generate.
+    #[builder(default = "1024")]
+    pub max_tokens: u32,
+
+    /// The OpenAI API features the deployment supports.
+    #[builder(default)]
+    pub compatibility: Compatibility,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl AzureProviderSettings {
+    /// Creates a new builder for `AzureProviderSettings`.
+    pub fn builder() -> AzureProviderSettingsBuilder {
+        AzureProviderSettingsBuilder::default()
+    }
+}
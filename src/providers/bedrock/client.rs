@@ -0,0 +1,403 @@
+//! This module provides a client for interacting with the Bedrock runtime
+//! API. It handles the construction and signing of requests, sending them to
+//! the `Converse` and `ConverseStream` endpoints, and parsing the responses.
+
+use std::time::SystemTime;
+
+use futures::{StreamExt, stream, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::credentials::AwsCredentials;
+use super::event_stream::{EventStreamDecoder, Message as EventMessage};
+use super::sigv4;
+use crate::core::retry::RetryPolicy;
+use crate::error::{ApiErrorDetails, Error, Result};
+
+/// A client for the Bedrock runtime API.
+#[derive(Debug, Clone)]
+pub struct BedrockClient {
+    client: Client,
+    base_url: String,
+    region: String,
+    credentials: Option<AwsCredentials>,
+    profile: Option<String>,
+    retry_policy: RetryPolicy,
+}
+
+impl BedrockClient {
+    /// Creates a new `BedrockClient` for the endpoint at the given base URL.
+    ///
+    /// When no credentials are given, they are loaded from the environment or
+    /// the given profile of the shared AWS files for every request.
+    pub fn new(
+        base_url: String,
+        region: String,
+        credentials: Option<AwsCredentials>,
+        profile: Option<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            base_url,
+            region,
+            credentials,
+            profile,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the `Converse` endpoint of the given model.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        model: &str,
+        request: &ConverseRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<ConverseResponse> {
+        let response = self.send(model, "converse", request, retry_policy).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request to the `ConverseStream` endpoint of the given model.
+    ///
+    /// The returned stream yields the events decoded from the binary event
+    /// stream of the response.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        model: &str,
+        request: &ConverseRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<StreamEvent>>> {
+        let response = self
+            .send(model, "converse-stream", request, retry_policy)
+            .await?;
+
+        let mut decoder = EventStreamDecoder::default();
+        let events = response
+            .bytes_stream()
+            .map(move |bytes| match bytes {
+                Ok(bytes) => match decoder.feed(&bytes) {
+                    Ok(messages) => messages.into_iter().map(parse_event).collect(),
+                    Err(err) => vec![Err(err)],
+                },
+                Err(err) => vec![Err(Error::from(err))],
+            })
+            .flat_map(stream::iter);
+
+        Ok(events.boxed())
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        model: &str,
+        operation: &str,
+        request: &ConverseRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let credentials = match &self.credentials {
+                    Some(credentials) => credentials.clone(),
+                    None => AwsCredentials::load(self.profile.as_deref())?,
+                };
+
+                let url = format!(
+                    "{}/model/{}/{operation}",
+                    self.base_url,
+                    encode_path_segment(model)
+                );
+                let mut http_request = self
+                    .client
+                    .post(url)
+                    .header("content-type", "application/json")
+                    .body(serde_json::to_vec(request)?)
+                    .build()?;
+                sigv4::sign(
+                    &mut http_request,
+                    &credentials,
+                    &self.region,
+                    "bedrock",
+                    SystemTime::now(),
+                )?;
+
+                let response = self.client.execute(http_request).await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// Percent-encodes a model ID or ARN for use as a path segment.
+fn encode_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Parses an event stream message into a stream event. Exceptions sent in
+/// the stream are returned as errors.
+fn parse_event(message: EventMessage) -> Result<StreamEvent> {
+    match message.header(":message-type") {
+        Some("event") => {
+            let event_type = message.header(":event-type").unwrap_or_default();
+            let payload = serde_json::from_slice(&message.payload)?;
+            Ok(match event_type {
+                "contentBlockStart" => {
+                    StreamEvent::ContentBlockStart(serde_json::from_value(payload)?)
+                }
+                "contentBlockDelta" => {
+                    StreamEvent::ContentBlockDelta(serde_json::from_value(payload)?)
+                }
+                "contentBlockStop" => {
+                    StreamEvent::ContentBlockStop(serde_json::from_value(payload)?)
+                }
+                "messageStop" => StreamEvent::MessageStop(serde_json::from_value(payload)?),
+                "metadata" => StreamEvent::Metadata(serde_json::from_value(payload)?),
+                _ => StreamEvent::Other,
+            })
+        }
+        _ => {
+            let kind = message
+                .header(":exception-type")
+                .or(message.header(":error-code"))
+                .unwrap_or("error");
+            let mut details =
+                ApiErrorDetails::parse(None, &String::from_utf8_lossy(&message.payload));
+            details.code = Some(kind.to_string());
+            Err(Error::from_api_error(details, None))
+        }
+    }
+}
+
+/// A request to the `Converse` and `ConverseStream` endpoints.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConverseRequest {
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub system: Vec<SystemContentBlock>,
+    pub inference_config: InferenceConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_config: Option<ToolConfig>,
+}
+
+/// A message in a conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Message {
+    pub role: String,
+    pub content: Vec<ContentBlock>,
+}
+
+/// A block of message content. Exactly one of the fields is set.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_use: Option<ToolUseBlock>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_result: Option<ToolResultBlock>,
+}
+
+/// A tool call made by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolUseBlock {
+    pub tool_use_id: String,
+    pub name: String,
+    pub input: serde_json::Value,
+}
+
+/// The result of a tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolResultBlock {
+    pub tool_use_id: String,
+    pub content: Vec<ToolResultContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// A block of tool result content.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ToolResultContent {
+    Text(String),
+    Json(serde_json::Value),
+}
+
+/// A block of the system prompt.
+#[derive(Debug, Serialize)]
+pub struct SystemContentBlock {
+    pub text: String,
+}
+
+/// Inference parameters supported by all models.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InferenceConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stop_sequences: Vec<String>,
+}
+
+/// The tools the model may call.
+#[derive(Debug, Serialize)]
+pub struct ToolConfig {
+    pub tools: Vec<Tool>,
+}
+
+/// A tool the model may call.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tool {
+    pub tool_spec: ToolSpecification,
+}
+
+/// The specification of a tool.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolSpecification {
+    pub name: String,
+    pub description: String,
+    pub input_schema: ToolInputSchema,
+}
+
+/// The JSON Schema of the input of a tool.
+#[derive(Debug, Serialize)]
+pub struct ToolInputSchema {
+    pub json: serde_json::Value,
+}
+
+/// A response from the `Converse` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConverseResponse {
+    pub output: ConverseOutput,
+    pub stop_reason: Option<String>,
+    pub usage: Option<TokenUsage>,
+}
+
+/// The output of the model.
+#[derive(Debug, Deserialize)]
+pub struct ConverseOutput {
+    pub message: Option<Message>,
+}
+
+/// Token usage reported by the Bedrock API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+    pub cache_read_input_tokens: Option<u32>,
+}
+
+/// An event of a `ConverseStream` response.
+#[derive(Debug)]
+pub enum StreamEvent {
+    ContentBlockStart(ContentBlockStartEvent),
+    ContentBlockDelta(ContentBlockDeltaEvent),
+    ContentBlockStop(ContentBlockStopEvent),
+    MessageStop(MessageStopEvent),
+    Metadata(MetadataEvent),
+    /// An event that carries nothing the provider uses, such as `messageStart`.
+    Other,
+}
+
+/// The start of a content block.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlockStartEvent {
+    pub content_block_index: u32,
+    pub start: ContentBlockStart,
+}
+
+/// The content a block starts with.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlockStart {
+    pub tool_use: Option<ToolUseStart>,
+}
+
+/// The start of a tool call.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolUseStart {
+    pub tool_use_id: String,
+    pub name: String,
+}
+
+/// An incremental update to a content block.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlockDeltaEvent {
+    pub content_block_index: u32,
+    pub delta: ContentBlockDelta,
+}
+
+/// The content added to a block.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlockDelta {
+    pub text: Option<String>,
+    pub tool_use: Option<ToolUseDelta>,
+}
+
+/// A fragment of the JSON encoded input of a tool call.
+#[derive(Debug, Deserialize)]
+pub struct ToolUseDelta {
+    pub input: String,
+}
+
+/// The end of a content block.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBlockStopEvent {
+    pub content_block_index: u32,
+}
+
+/// The end of the message.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageStopEvent {
+    pub stop_reason: String,
+}
+
+/// The metadata sent at the end of a stream.
+#[derive(Debug, Deserialize)]
+pub struct MetadataEvent {
+    pub usage: Option<TokenUsage>,
+}
@@ -0,0 +1,177 @@
+//! Resolves AWS credentials from the environment or the shared credentials
+//! and config files.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// AWS credentials used to sign requests.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AwsCredentials {
+    /// The access key ID.
+    pub access_key_id: String,
+
+    /// The secret access key.
+    pub secret_access_key: String,
+
+    /// The session token of temporary credentials.
+    pub session_token: Option<String>,
+}
+
+impl std::fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl AwsCredentials {
+    /// Creates new long-term credentials.
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+        }
+    }
+
+    /// Loads the credentials from the environment, falling back to the given
+    /// profile, or the `AWS_PROFILE` or `default` profile when it is `None`.
+    pub fn load(profile: Option<&str>) -> Result<Self> {
+        if let Some(credentials) = Self::from_env() {
+            return Ok(credentials);
+        }
+        Self::from_profile(profile)
+    }
+
+    /// Reads the credentials from the `AWS_ACCESS_KEY_ID`,
+    /// `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+        Some(Self {
+            access_key_id,
+            secret_access_key,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+        })
+    }
+
+    /// Reads the credentials of a profile from the shared credentials file,
+    /// then from the shared config file.
+    pub fn from_profile(profile: Option<&str>) -> Result<Self> {
+        let profile = profile
+            .map(str::to_string)
+            .or_else(|| std::env::var("AWS_PROFILE").ok())
+            .unwrap_or_else(|| "default".to_string());
+
+        let files = [
+            (
+                std::env::var_os("AWS_SHARED_CREDENTIALS_FILE").map(PathBuf::from),
+                ".aws/credentials",
+                profile.clone(),
+            ),
+            (
+                std::env::var_os("AWS_CONFIG_FILE").map(PathBuf::from),
+                ".aws/config",
+                match profile.as_str() {
+                    "default" => profile.clone(),
+                    _ => format!("profile {profile}"),
+                },
+            ),
+        ];
+
+        for (path, default_path, section) in files {
+            let Some(path) = path.or_else(|| home_dir().map(|home| home.join(default_path))) else {
+                continue;
+            };
+            let Ok(contents) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(credentials) = parse_profile(&contents, &section) {
+                return Ok(credentials);
+            }
+        }
+
+        Err(Error::MissingField(format!(
+            "AWS credentials (set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY or configure profile `{profile}`)"
+        )))
+    }
+}
+
+/// Returns the home directory of the current user.
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Reads the credentials in a section of an INI formatted AWS file.
+fn parse_profile(contents: &str, section: &str) -> Option<AwsCredentials> {
+    let mut values = HashMap::new();
+    let mut in_section = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_section = name.trim() == section;
+            continue;
+        }
+        if in_section && let Some((key, value)) = line.split_once('=') {
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+
+    Some(AwsCredentials {
+        access_key_id: values.remove("aws_access_key_id")?,
+        secret_access_key: values.remove("aws_secret_access_key")?,
+        session_token: values.remove("aws_session_token"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile() {
+        let contents = "\
+[default]
+aws_access_key_id = AKIDDEFAULT
+aws_secret_access_key = default-secret
+
+# temporary credentials
+[work]
+aws_access_key_id=AKIDWORK
+aws_secret_access_key=work-secret
+aws_session_token=work-token
+";
+
+        let credentials = parse_profile(contents, "work").unwrap();
+        assert_eq!(credentials.access_key_id, "AKIDWORK");
+        assert_eq!(credentials.session_token.as_deref(), Some("work-token"));
+
+        let credentials = parse_profile(contents, "default").unwrap();
+        assert_eq!(credentials.secret_access_key, "default-secret");
+        assert_eq!(credentials.session_token, None);
+
+        assert!(parse_profile(contents, "missing").is_none());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let credentials = AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI");
+        assert!(!format!("{credentials:?}").contains("wJalrXUtnFEMI"));
+    }
+}
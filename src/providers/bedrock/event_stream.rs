@@ -0,0 +1,210 @@
+//! A decoder for the binary `application/vnd.amazon.eventstream` framing used
+//! by streaming AWS APIs.
+//!
+//! Every message is laid out as follows, with integers in big-endian order:
+//!
+//! ```text
+//! total length (u32) | headers length (u32) | prelude CRC32 (u32)
+//! headers | payload | message CRC32 (u32)
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+
+/// The length of the prelude, including its checksum.
+const PRELUDE_LENGTH: usize = 12;
+
+/// The length of the checksum at the end of a message.
+const CRC_LENGTH: usize = 4;
+
+/// A decoded event stream message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// The string-valued headers of the message. Headers of other types are
+    /// skipped.
+    pub headers: HashMap<String, String>,
+
+    /// The payload of the message.
+    pub payload: Vec<u8>,
+}
+
+impl Message {
+    /// Returns the value of a header.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+/// An incremental decoder for event stream messages.
+///
+/// Bytes are fed in as they arrive from the network and every completed
+/// message is returned.
+#[derive(Debug, Default)]
+pub struct EventStreamDecoder {
+    buffer: Vec<u8>,
+}
+
+impl EventStreamDecoder {
+    /// Feeds a chunk of bytes into the decoder and returns all messages
+    /// completed by it. Fails if a message is malformed.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<Vec<Message>> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut messages = Vec::new();
+        while self.buffer.len() >= PRELUDE_LENGTH {
+            let total_length = read_u32(&self.buffer[0..4]) as usize;
+            if total_length < PRELUDE_LENGTH + CRC_LENGTH {
+                return Err(malformed("message is too short"));
+            }
+            if self.buffer.len() < total_length {
+                break;
+            }
+            let message: Vec<u8> = self.buffer.drain(..total_length).collect();
+            messages.push(decode_message(&message)?);
+        }
+        Ok(messages)
+    }
+}
+
+/// Decodes a complete message.
+fn decode_message(message: &[u8]) -> Result<Message> {
+    let headers_length = read_u32(&message[4..8]) as usize;
+    if read_u32(&message[8..12]) != crc32fast::hash(&message[..8]) {
+        return Err(malformed("prelude checksum mismatch"));
+    }
+    let crc_start = message.len() - CRC_LENGTH;
+    if read_u32(&message[crc_start..]) != crc32fast::hash(&message[..crc_start]) {
+        return Err(malformed("message checksum mismatch"));
+    }
+    let headers_end = PRELUDE_LENGTH + headers_length;
+    if headers_end > crc_start {
+        return Err(malformed("headers exceed the message"));
+    }
+
+    Ok(Message {
+        headers: decode_headers(&message[PRELUDE_LENGTH..headers_end])?,
+        payload: message[headers_end..crc_start].to_vec(),
+    })
+}
+
+/// Decodes the headers of a message, keeping the string-valued ones.
+fn decode_headers(mut bytes: &[u8]) -> Result<HashMap<String, String>> {
+    let mut headers = HashMap::new();
+    while !bytes.is_empty() {
+        let name_length = bytes[0] as usize;
+        let name = take(&mut bytes, 1 + name_length)?[1..].to_vec();
+        let value_type = take(&mut bytes, 1)?[0];
+        let value = match value_type {
+            // Booleans are encoded in the type.
+            0 | 1 => None,
+            2 => take(&mut bytes, 1).map(|_| None)?,
+            3 => take(&mut bytes, 2).map(|_| None)?,
+            4 => take(&mut bytes, 4).map(|_| None)?,
+            5 | 8 => take(&mut bytes, 8).map(|_| None)?,
+            9 => take(&mut bytes, 16).map(|_| None)?,
+            6 | 7 => {
+                let length = read_u16(take(&mut bytes, 2)?) as usize;
+                let value = take(&mut bytes, length)?;
+                (value_type == 7).then(|| String::from_utf8_lossy(value).into_owned())
+            }
+            other => return Err(malformed(&format!("unknown header type {other}"))),
+        };
+        if let Some(value) = value {
+            headers.insert(String::from_utf8_lossy(&name).into_owned(), value);
+        }
+    }
+    Ok(headers)
+}
+
+/// Splits off the first `length` bytes.
+fn take<'a>(bytes: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if bytes.len() < length {
+        return Err(malformed("header exceeds the headers section"));
+    }
+    let (head, tail) = bytes.split_at(length);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes(bytes.try_into().expect("four bytes"))
+}
+
+fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes(bytes.try_into().expect("two bytes"))
+}
+
+fn malformed(reason: &str) -> Error {
+    Error::ParseError(format!("malformed event stream message: {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a string header.
+    fn string_header(name: &str, value: &str) -> Vec<u8> {
+        let mut header = vec![name.len() as u8];
+        header.extend_from_slice(name.as_bytes());
+        header.push(7);
+        header.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        header.extend_from_slice(value.as_bytes());
+        header
+    }
+
+    /// Encodes a message with the given encoded headers.
+    fn encode(headers: &[u8], payload: &[u8]) -> Vec<u8> {
+        let total_length = PRELUDE_LENGTH + headers.len() + payload.len() + CRC_LENGTH;
+
+        let mut message = Vec::new();
+        message.extend_from_slice(&(total_length as u32).to_be_bytes());
+        message.extend_from_slice(&(headers.len() as u32).to_be_bytes());
+        message.extend_from_slice(&crc32fast::hash(&message).to_be_bytes());
+        message.extend_from_slice(headers);
+        message.extend_from_slice(payload);
+        message.extend_from_slice(&crc32fast::hash(&message).to_be_bytes());
+        message
+    }
+
+    #[test]
+    fn test_decodes_messages_split_across_chunks() {
+        let mut bytes = encode(
+            &string_header(":event-type", "messageStart"),
+            br#"{"role":"assistant"}"#,
+        );
+        bytes.extend(encode(&string_header(":event-type", "messageStop"), b"{}"));
+
+        let mut decoder = EventStreamDecoder::default();
+        let first = decoder.feed(&bytes[..10]).unwrap();
+        let rest = decoder.feed(&bytes[10..]).unwrap();
+
+        assert!(first.is_empty());
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[0].header(":event-type"), Some("messageStart"));
+        assert_eq!(rest[0].payload, br#"{"role":"assistant"}"#);
+        assert_eq!(rest[1].header(":event-type"), Some("messageStop"));
+    }
+
+    #[test]
+    fn test_skips_non_string_headers() {
+        let mut headers = vec![4, b'f', b'l', b'a', b'g', 0];
+        headers.extend(string_header(":message-type", "event"));
+
+        let messages = EventStreamDecoder::default()
+            .feed(&encode(&headers, b""))
+            .unwrap();
+
+        assert_eq!(messages[0].headers.len(), 1);
+        assert_eq!(messages[0].header(":message-type"), Some("event"));
+    }
+
+    #[test]
+    fn test_rejects_corrupted_messages() {
+        let mut bytes = encode(&string_header(":event-type", "messageStop"), b"{}");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        assert!(EventStreamDecoder::default().feed(&bytes).is_err());
+    }
+}
@@ -0,0 +1,465 @@
+//! This module provides the Amazon Bedrock provider, which implements the
+//! `LanguageModel` and `Provider` traits for the models hosted on Bedrock
+//! through the `Converse` and `ConverseStream` APIs.
+//!
+//! Requests are signed with AWS Signature Version 4, using credentials from
+//! the settings, the environment or the shared AWS files.
+
+pub mod client;
+pub mod credentials;
+pub mod event_stream;
+pub mod settings;
+pub mod sigv4;
+
+pub use credentials::AwsCredentials;
+pub use settings::BedrockProviderSettings;
+
+use self::client::{
+    BedrockClient, ContentBlock, ConverseRequest, InferenceConfig, Message, StreamEvent,
+    SystemContentBlock, TokenUsage, Tool, ToolConfig, ToolInputSchema, ToolResultBlock,
+    ToolResultContent, ToolSpecification, ToolUseBlock,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ToolCall, Usage,
+        },
+    },
+    error::Result,
+    providers::user_text,
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// The Amazon Bedrock provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Bedrock {
+    #[serde(skip)]
+    client: BedrockClient,
+    settings: BedrockProviderSettings,
+}
+
+impl Bedrock {
+    /// Creates a new `Bedrock` provider with the given settings.
+    pub fn new(settings: BedrockProviderSettings) -> Self {
+        let base_url = settings.base_url.clone().unwrap_or_else(|| {
+            format!("https://bedrock-runtime.{}.amazonaws.com", settings.region)
+        });
+        let client = BedrockClient::new(
+            base_url,
+            settings.region.clone(),
+            settings.credentials.clone(),
+            settings.profile.clone(),
+        )
+        .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Builds a `Converse` request from the provider-neutral call options.
+    fn converse_request(&self, options: LanguageModelCallOptions) -> Result<ConverseRequest> {
+        if options.settings.top_k.is_some() {
+            log::warn!("Bedrock does not support `top_k`, ignoring it");
+        }
+        if options.settings.presence_penalty.is_some()
+            || options.settings.frequency_penalty.is_some()
+        {
+            log::warn!("Bedrock does not support penalties, ignoring them");
+        }
+        if options.settings.seed.is_some() {
+            log::warn!("Bedrock does not support `seed`, ignoring it");
+        }
+
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                tool_spec: ToolSpecification {
+                    name: tool.name,
+                    description: tool.description,
+                    input_schema: ToolInputSchema {
+                        json: tool.parameters,
+                    },
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let (system, messages) = to_bedrock_messages(options.system, options.messages)?;
+        Ok(ConverseRequest {
+            messages,
+            system,
+            inference_config: InferenceConfig {
+                max_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+                temperature: options.settings.temperature,
+                top_p: options.settings.top_p,
+                stop_sequences: options.settings.stop_sequences,
+            },
+            tool_config: (!tools.is_empty()).then_some(ToolConfig { tools }),
+        })
+    }
+}
+
+impl Provider for Bedrock {}
+
+#[async_trait]
+impl LanguageModel for Bedrock {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.converse_request(options)?;
+
+        let response = self
+            .client
+            .generate_text(self.model_name(), &request, retry_policy.as_ref())
+            .await?;
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        let content = response.output.message.map(|message| message.content);
+        for block in content.unwrap_or_default() {
+            if let Some(block_text) = block.text {
+                text.push_str(&block_text);
+            }
+            if let Some(tool_use) = block.tool_use {
+                tool_calls.push(ToolCall {
+                    id: tool_use.tool_use_id,
+                    name: tool_use.name,
+                    arguments: tool_use.input,
+                });
+            }
+        }
+
+        Ok(LanguageModelResponse {
+            text,
+            model: Some(self.model_name().to_string()),
+            tool_calls,
+            finish_reason: to_finish_reason(response.stop_reason.as_deref()),
+            usage: response.usage.map(to_usage),
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.converse_request(options)?;
+
+        let events = self
+            .client
+            .stream_text(self.model_name(), &request, retry_policy.as_ref())
+            .await?;
+
+        let mut state = StreamState::default();
+        Ok(events
+            .map(move |event| match event {
+                Ok(event) => state.process(event),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts a Bedrock stop reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("end_turn" | "stop_sequence") => FinishReason::Stop,
+        Some("max_tokens") => FinishReason::Length,
+        Some("tool_use") => FinishReason::ToolCalls,
+        Some("guardrail_intervened" | "content_filtered") => FinishReason::ContentFilter,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts Bedrock token usage into normalized token usage.
+fn to_usage(usage: TokenUsage) -> Usage {
+    Usage {
+        prompt_tokens: usage.input_tokens,
+        completion_tokens: usage.output_tokens,
+        total_tokens: usage.total_tokens,
+        cached_tokens: usage.cache_read_input_tokens,
+        ..Default::default()
+    }
+}
+
+/// Parses the JSON encoded input of a streamed tool call. Tools without
+/// parameters stream no input at all, which is treated as an empty object.
+fn parse_input(input: &str) -> Result<serde_json::Value> {
+    if input.trim().is_empty() {
+        return Ok(serde_json::Value::Object(Default::default()));
+    }
+    Ok(serde_json::from_str(input)?)
+}
+
+/// A tool call whose input is still being streamed.
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    input: String,
+}
+
+/// Converts streamed Bedrock events into provider-neutral stream chunks,
+/// assembling tool call fragments into complete calls.
+#[derive(Debug, Default)]
+struct StreamState {
+    tool_calls: BTreeMap<u32, PendingToolCall>,
+}
+
+impl StreamState {
+    fn process(&mut self, event: StreamEvent) -> Vec<LanguageModelStreamChunk> {
+        match event {
+            StreamEvent::ContentBlockStart(event) => {
+                if let Some(tool_use) = event.start.tool_use {
+                    self.tool_calls.insert(
+                        event.content_block_index,
+                        PendingToolCall {
+                            id: tool_use.tool_use_id,
+                            name: tool_use.name,
+                            input: String::new(),
+                        },
+                    );
+                }
+                vec![]
+            }
+            StreamEvent::ContentBlockDelta(event) => {
+                if let Some(tool_use) = event.delta.tool_use
+                    && let Some(pending) = self.tool_calls.get_mut(&event.content_block_index)
+                {
+                    pending.input.push_str(&tool_use.input);
+                }
+                event
+                    .delta
+                    .text
+                    .filter(|text| !text.is_empty())
+                    .map(LanguageModelStreamChunk::TextDelta)
+                    .into_iter()
+                    .collect()
+            }
+            StreamEvent::ContentBlockStop(event) => {
+                match self.tool_calls.remove(&event.content_block_index) {
+                    Some(call) => vec![match parse_input(&call.input) {
+                        Ok(arguments) => LanguageModelStreamChunk::ToolCall(ToolCall {
+                            id: call.id,
+                            name: call.name,
+                            arguments,
+                        }),
+                        Err(err) => LanguageModelStreamChunk::Error(err.to_string()),
+                    }],
+                    None => vec![],
+                }
+            }
+            StreamEvent::MessageStop(event) => vec![LanguageModelStreamChunk::Finish {
+                finish_reason: to_finish_reason(Some(&event.stop_reason)),
+            }],
+            StreamEvent::Metadata(event) => event
+                .usage
+                .map(|usage| LanguageModelStreamChunk::Usage(to_usage(usage)))
+                .into_iter()
+                .collect(),
+            StreamEvent::Other => vec![],
+        }
+    }
+}
+
+/// Converts the provider-neutral conversation into Bedrock messages.
+///
+/// System messages are hoisted into the system prompt, tool results are sent
+/// as user messages, and consecutive messages of the same role are merged, as
+/// the `Converse` API requires the roles to alternate.
+fn to_bedrock_messages(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<(Vec<SystemContentBlock>, Vec<Message>)> {
+    let mut system_parts: Vec<String> = system.into_iter().collect();
+    let mut result: Vec<Message> = Vec::new();
+    let text = |text: String| ContentBlock {
+        text: Some(text),
+        ..Default::default()
+    };
+
+    for message in messages {
+        let (role, content) = match message {
+            types::Message::System { content } => {
+                system_parts.push(content);
+                continue;
+            }
+            types::Message::User { content } => {
+                ("user", vec![text(user_text("Bedrock", content)?)])
+            }
+            types::Message::Assistant {
+                content,
+                tool_calls,
+            } => {
+                let content_block = (!content.is_empty()).then(|| text(content));
+                let tool_uses = tool_calls.into_iter().map(|call| ContentBlock {
+                    tool_use: Some(ToolUseBlock {
+                        tool_use_id: call.id,
+                        name: call.name,
+                        input: call.arguments,
+                    }),
+                    ..Default::default()
+                });
+                (
+                    "assistant",
+                    content_block.into_iter().chain(tool_uses).collect(),
+                )
+            }
+            types::Message::Tool {
+                tool_call_id,
+                content,
+                is_error,
+            } => (
+                "user",
+                vec![ContentBlock {
+                    tool_result: Some(ToolResultBlock {
+                        tool_use_id: tool_call_id,
+                        content: vec![ToolResultContent::Text(content)],
+                        status: is_error.then(|| "error".to_string()),
+                    }),
+                    ..Default::default()
+                }],
+            ),
+        };
+
+        match result.last_mut() {
+            Some(last) if last.role == role => last.content.extend(content),
+            _ => result.push(Message {
+                role: role.to_string(),
+                content,
+            }),
+        }
+    }
+
+    let system = system_parts
+        .into_iter()
+        .map(|text| SystemContentBlock { text })
+        .collect();
+    Ok((system, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::bedrock::client::{
+        ContentBlockDelta, ContentBlockDeltaEvent, ContentBlockStart, ContentBlockStartEvent,
+        ContentBlockStopEvent, MessageStopEvent, ToolUseDelta, ToolUseStart,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_to_bedrock_messages_merges_roles_and_hoists_system() {
+        let (system, messages) = to_bedrock_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::system("use metric units"),
+                types::Message::user("weather in Paris?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "tooluse_1".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("tooluse_1", "21"),
+                types::Message::user("and in Rome?"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(system.len(), 2);
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            json!([
+                {"role": "user", "content": [{"text": "weather in Paris?"}]},
+                {"role": "assistant", "content": [{"toolUse": {
+                    "toolUseId": "tooluse_1", "name": "get_weather", "input": {"city": "Paris"}
+                }}]},
+                {"role": "user", "content": [
+                    {"toolResult": {"toolUseId": "tooluse_1", "content": [{"text": "21"}]}},
+                    {"text": "and in Rome?"}
+                ]}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_to_bedrock_messages_marks_failed_tool_results() {
+        let (_, messages) = to_bedrock_messages(
+            None,
+            vec![types::Message::tool_error(
+                "tooluse_1",
+                "Unknown tool: get_time",
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            json!([{"role": "user", "content": [{"toolResult": {
+                "toolUseId": "tooluse_1",
+                "content": [{"text": "Unknown tool: get_time"}],
+                "status": "error"
+            }}]}])
+        );
+    }
+
+    #[test]
+    fn test_stream_state_assembles_tool_calls() {
+        let mut state = StreamState::default();
+        let mut chunks = state.process(StreamEvent::ContentBlockStart(ContentBlockStartEvent {
+            content_block_index: 1,
+            start: ContentBlockStart {
+                tool_use: Some(ToolUseStart {
+                    tool_use_id: "tooluse_1".to_string(),
+                    name: "get_weather".to_string(),
+                }),
+            },
+        }));
+        for input in [r#"{"city":"#, r#" "Paris"}"#] {
+            chunks.extend(
+                state.process(StreamEvent::ContentBlockDelta(ContentBlockDeltaEvent {
+                    content_block_index: 1,
+                    delta: ContentBlockDelta {
+                        text: None,
+                        tool_use: Some(ToolUseDelta {
+                            input: input.to_string(),
+                        }),
+                    },
+                })),
+            );
+        }
+        chunks.extend(
+            state.process(StreamEvent::ContentBlockStop(ContentBlockStopEvent {
+                content_block_index: 1,
+            })),
+        );
+        chunks.extend(state.process(StreamEvent::MessageStop(MessageStopEvent {
+            stop_reason: "tool_use".to_string(),
+        })));
+
+        assert_eq!(
+            chunks,
+            vec![
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "tooluse_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+            ]
+        );
+    }
+}
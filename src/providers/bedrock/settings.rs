@@ -0,0 +1,63 @@
+//! Defines the settings for the Amazon Bedrock provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use super::credentials::AwsCredentials;
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Amazon Bedrock provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct BedrockProviderSettings {
+    /// The AWS region of the Bedrock endpoint. Defaults to the `AWS_REGION`
+    /// or `AWS_DEFAULT_REGION` environment variable, then `us-east-1`.
+    #[builder(default = "default_region()")]
+    pub region: String,
+
+    /// The ID of the model, or the ARN of an inference profile, to use.
+    #[builder(default = "\"anthropic.claude-3-5-sonnet-20240620-v1:0\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"bedrock\".to_string()")]
+    pub provider_name: String,
+
+    /// The URL of the Bedrock runtime endpoint. Defaults to the endpoint of
+    /// the region.
+    #[builder(default, setter(strip_option))]
+    pub base_url: Option<String>,
+
+    /// The credentials used to sign requests. When unset, they are loaded
+    /// from the environment or the shared AWS files for every request.
+    #[builder(default, setter(strip_option))]
+    pub credentials: Option<AwsCredentials>,
+
+    /// The profile of the shared AWS files to load credentials from. Defaults
+    /// to the `AWS_PROFILE` environment variable, then `default`.
+    #[builder(default, setter(strip_option))]
+    pub profile: Option<String>,
+
+    /// The maximum number of tokens to generate. Uses the model's default when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl BedrockProviderSettings {
+    /// Creates a new builder for `BedrockProviderSettings`.
+    pub fn builder() -> BedrockProviderSettingsBuilder {
+        BedrockProviderSettingsBuilder::default()
+    }
+}
+
+fn default_region() -> String {
+    std::env::var("AWS_REGION")
+        .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+        .unwrap_or_else(|_| "us-east-1".to_string())
+}
@@ -0,0 +1,229 @@
+//! Signs requests with AWS Signature Version 4.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use reqwest::header::{AUTHORIZATION, HeaderValue};
+use sha2::{Digest, Sha256};
+
+use super::credentials::AwsCredentials;
+use crate::error::{Error, Result};
+
+/// Signs a request for the given region and service at the given time.
+///
+/// The `host` and `content-type` headers and all `x-amz-*` headers are signed,
+/// and the `x-amz-date`, `x-amz-security-token` and `authorization` headers
+/// are added to the request.
+pub fn sign(
+    request: &mut reqwest::Request,
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    time: SystemTime,
+) -> Result<()> {
+    let (amz_date, date) = format_time(time);
+    let headers = request.headers_mut();
+    headers.insert("x-amz-date", header_value(&amz_date)?);
+    if let Some(token) = &credentials.session_token {
+        headers.insert("x-amz-security-token", header_value(token)?);
+    }
+
+    let url = request.url();
+    let host = match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+    let mut signed: Vec<(String, String)> = vec![("host".to_string(), host)];
+    for (name, value) in request.headers() {
+        let name = name.as_str();
+        if name == "content-type" || name.starts_with("x-amz-") {
+            let value = value
+                .to_str()
+                .map_err(|err| Error::Other(err.to_string()))?;
+            signed.push((name.to_string(), value.trim().to_string()));
+        }
+    }
+    signed.sort();
+
+    let canonical_headers: String = signed
+        .iter()
+        .map(|(name, value)| format!("{name}:{value}\n"))
+        .collect();
+    let signed_headers = signed
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .unwrap_or_default();
+
+    let canonical_request = [
+        request.method().as_str(),
+        &canonical_uri(url.path()),
+        &canonical_query(url),
+        &canonical_headers,
+        &signed_headers,
+        &hex::encode(Sha256::digest(body)),
+    ]
+    .join("\n");
+
+    let scope = format!("{date}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = [date.as_str(), region, service, "aws4_request"]
+        .iter()
+        .fold(
+            format!("AWS4{}", credentials.secret_access_key).into_bytes(),
+            |key, part| hmac(&key, part.as_bytes()),
+        );
+    let signature = hex::encode(hmac(&key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.access_key_id
+    );
+    request
+        .headers_mut()
+        .insert(AUTHORIZATION, header_value(&authorization)?);
+
+    Ok(())
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|err| Error::Other(err.to_string()))
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Encodes every segment of the already encoded path once more, as SigV4
+/// requires for all services but S3.
+fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    path.split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Sorts and encodes the query parameters.
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| (uri_encode(&name), uri_encode(&value)))
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encodes every byte except the unreserved characters.
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Formats a time as the `YYYYMMDD'T'HHMMSS'Z'` timestamp and `YYYYMMDD` date
+/// used by SigV4.
+fn format_time(time: SystemTime) -> (String, String) {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let (days, seconds) = ((seconds / 86_400) as i64, seconds % 86_400);
+
+    // Converts days since the epoch into a civil date.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let date = format!("{year:04}{month:02}{day:02}");
+    let amz_date = format!(
+        "{date}T{:02}{:02}{:02}Z",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    (amz_date, date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// 2015-08-30T12:36:00Z, the time used by the AWS SigV4 test suite.
+    fn test_suite_time() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_440_938_160)
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(
+            format_time(test_suite_time()),
+            ("20150830T123600Z".to_string(), "20150830".to_string())
+        );
+        assert_eq!(format_time(UNIX_EPOCH).1, "19700101");
+    }
+
+    #[test]
+    fn test_sign_matches_aws_test_suite() {
+        let credentials =
+            AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY");
+        let mut request = reqwest::Client::new()
+            .get("https://example.amazonaws.com/")
+            .build()
+            .unwrap();
+
+        sign(
+            &mut request,
+            &credentials,
+            "us-east-1",
+            "service",
+            test_suite_time(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.headers()[AUTHORIZATION],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn test_canonical_uri_encodes_segments_twice() {
+        assert_eq!(
+            canonical_uri("/model/anthropic.claude-v2%3A1/converse"),
+            "/model/anthropic.claude-v2%253A1/converse"
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! This module provides a client for interacting with the Cohere v2 API.
+//! It handles the construction of requests, sending them to the chat, rerank
+//! and embed endpoints, and parsing the responses.
+
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::{Error, Result};
+use crate::providers::cohere::settings::EmbedInputType;
+use crate::providers::sse;
+
+/// A client for the Cohere API.
+#[derive(Debug, Clone)]
+pub struct CohereClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl CohereClient {
+    /// Creates a new `CohereClient` for the API at the given base URL.
+    pub fn new(api_key: String, base_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            api_key,
+            base_url,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the chat endpoint.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<ChatResponse> {
+        self.post("chat", request, retry_policy)
+            .await?
+            .json()
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Sends a streaming request to the chat endpoint.
+    ///
+    /// The returned stream yields the parsed server-sent events.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<StreamEvent>>> {
+        let response = self.post("chat", request, retry_policy).await?;
+
+        let events = sse::data_stream(response).map(|data| Ok(serde_json::from_str(&data?)?));
+
+        Ok(events.boxed())
+    }
+
+    /// Sends a request to the rerank endpoint.
+    pub async fn rerank(&self, request: &RerankRequest) -> Result<RerankResponse> {
+        self.send("rerank", request).await
+    }
+
+    /// Sends a request to the embed endpoint.
+    pub async fn embed(&self, request: &EmbedRequest) -> Result<EmbedResponse> {
+        self.send("embed", request).await
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        request: &impl Serialize,
+    ) -> Result<T> {
+        Ok(self.post(endpoint, request, None).await?.json().await?)
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn post(
+        &self,
+        endpoint: &str,
+        request: &impl Serialize,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let response = self
+                    .client
+                    .post(format!("{}/{endpoint}", self.base_url))
+                    .bearer_auth(&self.api_key)
+                    .json(request)
+                    .send()
+                    .await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// A request to the chat endpoint.
+#[derive(Debug, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(rename = "p", skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(rename = "k", skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stop_sequences: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    pub stream: bool,
+}
+
+/// A message in a chat request.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Message {
+    pub role: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+/// The format the model must respond in.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    Text,
+    JsonObject {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        json_schema: Option<serde_json::Value>,
+    },
+}
+
+/// A tool that the model may call.
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionDefinition,
+}
+
+/// The definition of a function that the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool call generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionCall,
+}
+
+/// The function that the model called.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments to call the function with, as a JSON encoded string.
+    pub arguments: String,
+}
+
+/// A response from the chat endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ChatResponse {
+    pub id: String,
+    pub finish_reason: Option<String>,
+    pub message: AssistantMessage,
+    pub usage: Option<Usage>,
+}
+
+/// The message generated by the model.
+#[derive(Debug, Deserialize)]
+pub struct AssistantMessage {
+    #[serde(default)]
+    pub content: Vec<ContentBlock>,
+    pub tool_plan: Option<String>,
+    #[serde(default)]
+    pub tool_calls: Vec<ToolCall>,
+}
+
+/// A block of generated content.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    Text {
+        text: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Token usage reported by the Cohere API.
+#[derive(Debug, Deserialize)]
+pub struct Usage {
+    pub billed_units: Option<TokenCounts>,
+    pub tokens: Option<TokenCounts>,
+}
+
+/// Numbers of input and output tokens.
+#[derive(Debug, Deserialize)]
+pub struct TokenCounts {
+    #[serde(default)]
+    pub input_tokens: f64,
+    #[serde(default)]
+    pub output_tokens: f64,
+}
+
+/// An event of a streamed chat response.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum StreamEvent {
+    ContentDelta {
+        delta: StreamDelta,
+    },
+    ToolCallStart {
+        index: u32,
+        delta: StreamDelta,
+    },
+    ToolCallDelta {
+        index: u32,
+        delta: StreamDelta,
+    },
+    ToolCallEnd {
+        index: u32,
+    },
+    MessageEnd {
+        delta: StreamDelta,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// The payload of a streamed event.
+#[derive(Debug, Default, Deserialize)]
+pub struct StreamDelta {
+    pub message: Option<DeltaMessage>,
+    pub finish_reason: Option<String>,
+    pub usage: Option<Usage>,
+}
+
+/// An incremental update to the generated message.
+#[derive(Debug, Default, Deserialize)]
+pub struct DeltaMessage {
+    pub content: Option<DeltaContent>,
+    pub tool_calls: Option<DeltaToolCall>,
+}
+
+/// An incremental update to the content of the generated message.
+#[derive(Debug, Default, Deserialize)]
+pub struct DeltaContent {
+    pub text: Option<String>,
+}
+
+/// A fragment of a tool call. The id and function name are only sent when
+/// the call starts, while the arguments are spread across fragments.
+#[derive(Debug, Default, Deserialize)]
+pub struct DeltaToolCall {
+    pub id: Option<String>,
+    pub function: Option<DeltaFunction>,
+}
+
+/// A fragment of a function call.
+#[derive(Debug, Default, Deserialize)]
+pub struct DeltaFunction {
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+/// A request to the rerank endpoint.
+#[derive(Debug, Serialize)]
+pub struct RerankRequest {
+    pub model: String,
+    pub query: String,
+    pub documents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_n: Option<u32>,
+}
+
+/// A response from the rerank endpoint.
+#[derive(Debug, Deserialize)]
+pub struct RerankResponse {
+    pub id: Option<String>,
+    pub results: Vec<RerankResult>,
+}
+
+/// The relevance of a document to the query.
+#[derive(Debug, Deserialize)]
+pub struct RerankResult {
+    pub index: usize,
+    pub relevance_score: f32,
+}
+
+/// A request to the embed endpoint.
+#[derive(Debug, Serialize)]
+pub struct EmbedRequest {
+    pub model: String,
+    pub texts: Vec<String>,
+    pub input_type: EmbedInputType,
+    pub embedding_types: Vec<String>,
+}
+
+/// A response from the embed endpoint.
+#[derive(Debug, Deserialize)]
+pub struct EmbedResponse {
+    pub id: Option<String>,
+    pub embeddings: Embeddings,
+    pub meta: Option<EmbedMeta>,
+}
+
+/// The metadata of a response from the embed endpoint.
+#[derive(Debug, Deserialize)]
+pub struct EmbedMeta {
+    pub billed_units: Option<TokenCounts>,
+}
+
+/// The embeddings of the texts, by embedding type.
+#[derive(Debug, Deserialize)]
+pub struct Embeddings {
+    #[serde(default)]
+    pub float: Vec<Vec<f32>>,
+}
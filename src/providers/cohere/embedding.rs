@@ -0,0 +1,67 @@
+//! This module provides the Cohere embedding model, which implements the
+//! `EmbeddingModel` trait for the Cohere v2 `/embed` endpoint.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use super::client::{CohereClient, EmbedRequest};
+use super::settings::CohereEmbeddingSettings;
+use crate::{
+    core::{
+        embedding_model::EmbeddingModel,
+        types::{EmbeddingModelCallOptions, EmbeddingModelResponse, EmbeddingUsage},
+    },
+    error::Result,
+};
+
+/// A Cohere embedding model.
+#[derive(Debug, Clone, Serialize)]
+pub struct CohereEmbeddingModel {
+    #[serde(skip)]
+    client: CohereClient,
+    settings: CohereEmbeddingSettings,
+}
+
+impl CohereEmbeddingModel {
+    /// Creates a new `CohereEmbeddingModel` that sends its requests with the
+    /// given client.
+    pub(crate) fn new(client: CohereClient, settings: CohereEmbeddingSettings) -> Self {
+        Self { client, settings }
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for CohereEmbeddingModel {
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn max_embeddings_per_call(&self) -> Option<usize> {
+        Some(self.settings.max_embeddings_per_call)
+    }
+
+    async fn embed(&self, options: EmbeddingModelCallOptions) -> Result<EmbeddingModelResponse> {
+        let request = EmbedRequest {
+            model: self.settings.model_name.clone(),
+            texts: options.values,
+            input_type: self.settings.input_type,
+            embedding_types: vec!["float".to_string()],
+        };
+
+        let response = self.client.embed(&request).await?;
+
+        Ok(EmbeddingModelResponse {
+            embeddings: response.embeddings.float,
+            usage: response
+                .meta
+                .and_then(|meta| meta.billed_units)
+                .map(|units| EmbeddingUsage {
+                    tokens: units.input_tokens as u32,
+                }),
+        })
+    }
+}
@@ -0,0 +1,435 @@
+//! This module provides the Cohere provider, which implements the `LanguageModel`
+//! and `Provider` traits for the Cohere v2 chat API, gives access to its
+//! rerank endpoint, and creates embedding models for its embed endpoint.
+
+pub mod client;
+pub mod embedding;
+pub mod settings;
+
+pub use embedding::CohereEmbeddingModel;
+pub use settings::{CohereEmbeddingSettings, CohereProviderSettings, EmbedInputType};
+
+use self::client::{
+    ChatRequest, CohereClient, ContentBlock, FunctionCall, FunctionDefinition, Message,
+    RerankRequest, StreamEvent, Tool,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
+    },
+    error::Result,
+    providers::{parse_tool_arguments, user_text},
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The Cohere provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Cohere {
+    #[serde(skip)]
+    client: CohereClient,
+    settings: CohereProviderSettings,
+}
+
+/// A document ranked by its relevance to a query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankedDocument {
+    /// The index of the document in the documents that were ranked.
+    pub index: usize,
+
+    /// The document.
+    pub document: String,
+
+    /// The relevance of the document to the query, between 0 and 1.
+    pub relevance_score: f32,
+}
+
+impl Cohere {
+    /// Creates a new `Cohere` provider with the given settings.
+    pub fn new(settings: CohereProviderSettings) -> Self {
+        let client = CohereClient::new(settings.api_key.to_string(), settings.base_url.to_string())
+            .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Ranks documents by their relevance to a query, most relevant first.
+    /// Only the `top_n` most relevant documents are returned when it is set.
+    pub async fn rerank(
+        &self,
+        query: impl Into<String>,
+        documents: Vec<String>,
+        top_n: Option<u32>,
+    ) -> Result<Vec<RankedDocument>> {
+        let request = RerankRequest {
+            model: self.settings.rerank_model_name.clone(),
+            query: query.into(),
+            documents,
+            top_n,
+        };
+
+        let response = self.client.rerank(&request).await?;
+
+        Ok(response
+            .results
+            .into_iter()
+            .filter_map(|result| {
+                Some(RankedDocument {
+                    index: result.index,
+                    document: request.documents.get(result.index)?.clone(),
+                    relevance_score: result.relevance_score,
+                })
+            })
+            .collect())
+    }
+
+    /// Creates an embedding model that shares the API key, base URL and
+    /// retry policy of this provider.
+    pub fn embedding_model(&self, settings: CohereEmbeddingSettings) -> CohereEmbeddingModel {
+        CohereEmbeddingModel::new(self.client.clone(), settings)
+    }
+
+    /// Builds a chat request from the provider-neutral call options.
+    fn chat_request(&self, options: LanguageModelCallOptions, stream: bool) -> Result<ChatRequest> {
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                kind: "function".to_string(),
+                function: FunctionDefinition {
+                    name: tool.name,
+                    description: tool.description,
+                    parameters: tool.parameters,
+                },
+            })
+            .collect::<Vec<_>>();
+        let response_format = options.response_format.map(|format| match format {
+            ResponseFormat::Text => client::ResponseFormat::Text,
+            ResponseFormat::Json { schema, .. } => client::ResponseFormat::JsonObject {
+                json_schema: schema,
+            },
+        });
+
+        Ok(ChatRequest {
+            model: self.model_name().to_string(),
+            messages: to_cohere_messages(options.system, options.messages)?,
+            tools: (!tools.is_empty()).then_some(tools),
+            response_format,
+            max_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+            temperature: options.settings.temperature,
+            top_p: options.settings.top_p,
+            top_k: options.settings.top_k,
+            presence_penalty: options.settings.presence_penalty,
+            frequency_penalty: options.settings.frequency_penalty,
+            stop_sequences: options.settings.stop_sequences,
+            seed: options.settings.seed,
+            stream,
+        })
+    }
+}
+
+impl Provider for Cohere {}
+
+#[async_trait]
+impl LanguageModel for Cohere {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, false)?;
+
+        let response = self
+            .client
+            .generate_text(&request, retry_policy.as_ref())
+            .await?;
+
+        let text = response
+            .message
+            .content
+            .into_iter()
+            .filter_map(|block| match block {
+                ContentBlock::Text { text } => Some(text),
+                ContentBlock::Unknown => None,
+            })
+            .collect();
+        let tool_calls = response
+            .message
+            .tool_calls
+            .into_iter()
+            .map(|call| ToolCall {
+                id: call.id,
+                name: call.function.name,
+                arguments: parse_tool_arguments(&call.function.arguments),
+            })
+            .collect();
+
+        Ok(LanguageModelResponse {
+            text,
+            model: Some(self.model_name().to_string()),
+            tool_calls,
+            finish_reason: to_finish_reason(response.finish_reason.as_deref()),
+            usage: response.usage.as_ref().and_then(to_usage),
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, true)?;
+
+        let events = self
+            .client
+            .stream_text(&request, retry_policy.as_ref())
+            .await?;
+
+        let mut state = StreamState::default();
+        Ok(events
+            .map(move |event| match event {
+                Ok(event) => state.process(event),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts a Cohere finish reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("COMPLETE" | "STOP_SEQUENCE") => FinishReason::Stop,
+        Some("MAX_TOKENS") => FinishReason::Length,
+        Some("TOOL_CALL") => FinishReason::ToolCalls,
+        Some("ERROR") => FinishReason::Error,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts Cohere token usage into normalized token usage, preferring the
+/// actual token counts over the billed ones.
+fn to_usage(usage: &client::Usage) -> Option<Usage> {
+    let tokens = usage.tokens.as_ref().or(usage.billed_units.as_ref())?;
+    let prompt_tokens = tokens.input_tokens as u32;
+    let completion_tokens = tokens.output_tokens as u32;
+    Some(Usage {
+        prompt_tokens,
+        completion_tokens,
+        total_tokens: prompt_tokens.saturating_add(completion_tokens),
+        ..Default::default()
+    })
+}
+
+/// A tool call whose arguments are still being streamed.
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// Converts streamed Cohere events into provider-neutral stream chunks,
+/// assembling tool call fragments into complete calls.
+#[derive(Debug, Default)]
+struct StreamState {
+    tool_calls: BTreeMap<u32, PendingToolCall>,
+}
+
+impl StreamState {
+    fn process(&mut self, event: StreamEvent) -> Vec<LanguageModelStreamChunk> {
+        match event {
+            StreamEvent::ContentDelta { delta } => delta
+                .message
+                .and_then(|message| message.content)
+                .and_then(|content| content.text)
+                .filter(|text| !text.is_empty())
+                .map(LanguageModelStreamChunk::TextDelta)
+                .into_iter()
+                .collect(),
+            StreamEvent::ToolCallStart { index, delta }
+            | StreamEvent::ToolCallDelta { index, delta } => {
+                let call = delta
+                    .message
+                    .and_then(|message| message.tool_calls)
+                    .unwrap_or_default();
+                let pending = self.tool_calls.entry(index).or_default();
+                if let Some(id) = call.id {
+                    pending.id = id;
+                }
+                if let Some(function) = call.function {
+                    pending.name.push_str(&function.name.unwrap_or_default());
+                    pending
+                        .arguments
+                        .push_str(&function.arguments.unwrap_or_default());
+                }
+                vec![]
+            }
+            StreamEvent::ToolCallEnd { index } => match self.tool_calls.remove(&index) {
+                Some(call) => vec![LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: call.id,
+                    name: call.name,
+                    arguments: parse_tool_arguments(&call.arguments),
+                })],
+                None => vec![],
+            },
+            StreamEvent::MessageEnd { delta } => {
+                let mut chunks = vec![LanguageModelStreamChunk::Finish {
+                    finish_reason: to_finish_reason(delta.finish_reason.as_deref()),
+                }];
+                if let Some(usage) = delta.usage.as_ref().and_then(to_usage) {
+                    chunks.push(LanguageModelStreamChunk::Usage(usage));
+                }
+                chunks
+            }
+            StreamEvent::Unknown => vec![],
+        }
+    }
+}
+
+/// Converts the provider-neutral conversation into Cohere chat messages,
+/// placing the system prompt first.
+fn to_cohere_messages(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<Vec<Message>> {
+    let message = |role: &str, content: Option<String>| Message {
+        role: role.to_string(),
+        content,
+        tool_calls: None,
+        tool_call_id: None,
+    };
+
+    let system = system.map(types::Message::system);
+    system
+        .into_iter()
+        .chain(messages)
+        .map(|msg| {
+            Ok(match msg {
+                types::Message::System { content } => message("system", Some(content)),
+                types::Message::User { content } => {
+                    message("user", Some(user_text("Cohere", content)?))
+                }
+                types::Message::Assistant {
+                    content,
+                    tool_calls,
+                } if !tool_calls.is_empty() => {
+                    let tool_calls = tool_calls
+                        .into_iter()
+                        .map(|call| client::ToolCall {
+                            id: call.id,
+                            kind: "function".to_string(),
+                            function: FunctionCall {
+                                name: call.name,
+                                arguments: call.arguments.to_string(),
+                            },
+                        })
+                        .collect();
+                    Message {
+                        tool_calls: Some(tool_calls),
+                        ..message("assistant", (!content.is_empty()).then_some(content))
+                    }
+                }
+                types::Message::Assistant { content, .. } => message("assistant", Some(content)),
+                types::Message::Tool {
+                    tool_call_id,
+                    content,
+                    ..
+                } => Message {
+                    tool_call_id: Some(tool_call_id),
+                    ..message("tool", Some(content))
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_cohere_messages_encodes_tool_calls() {
+        let messages = to_cohere_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::user("weather in Paris?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "get_weather_1".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("get_weather_1", "21"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            json!([
+                {"role": "system", "content": "be brief"},
+                {"role": "user", "content": "weather in Paris?"},
+                {"role": "assistant", "tool_calls": [{
+                    "id": "get_weather_1",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]},
+                {"role": "tool", "content": "21", "tool_call_id": "get_weather_1"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_stream_state_assembles_tool_calls_and_usage() {
+        let events = [
+            r#"{"type":"message-start","id":"1","delta":{"message":{"role":"assistant"}}}"#,
+            r#"{"type":"tool-plan-delta","delta":{"message":{"tool_plan":"I will check."}}}"#,
+            r#"{"type":"tool-call-start","index":0,"delta":{"message":{"tool_calls":{"id":"get_weather_1","type":"function","function":{"name":"get_weather","arguments":""}}}}}"#,
+            r#"{"type":"tool-call-delta","index":0,"delta":{"message":{"tool_calls":{"function":{"arguments":"{\"city\": \"Paris\"}"}}}}}"#,
+            r#"{"type":"tool-call-end","index":0}"#,
+            r#"{"type":"message-end","delta":{"finish_reason":"TOOL_CALL","usage":{"billed_units":{"input_tokens":8,"output_tokens":18},"tokens":{"input_tokens":10,"output_tokens":20}}}}"#,
+        ];
+
+        let mut state = StreamState::default();
+        let chunks: Vec<_> = events
+            .iter()
+            .flat_map(|event| state.process(serde_json::from_str(event).unwrap()))
+            .collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "get_weather_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+                LanguageModelStreamChunk::Usage(Usage {
+                    prompt_tokens: 10,
+                    completion_tokens: 20,
+                    total_tokens: 30,
+                    ..Default::default()
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Defines the settings for the Cohere provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Cohere provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct CohereProviderSettings {
+    /// The API key for the Cohere API.
+    #[builder(default = "std::env::var(\"COHERE_API_KEY\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// The model to use for text generation.
+    #[builder(default = "\"command-a-03-2025\".to_string()")]
+    pub model_name: String,
+
+    /// The model to use for reranking documents.
+    #[builder(default = "\"rerank-v3.5\".to_string()")]
+    pub rerank_model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"cohere\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the Cohere v2 API.
+    #[builder(default = "\"https://api.cohere.com/v2\".to_string()")]
+    pub base_url: String,
+
+    /// The maximum number of tokens to generate. Uses the model's limit when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl CohereProviderSettings {
+    /// Creates a new builder for `CohereProviderSettings`.
+    pub fn builder() -> CohereProviderSettingsBuilder {
+        CohereProviderSettingsBuilder::default()
+    }
+}
+
+/// Settings for a Cohere embedding model, created with
+/// [`Cohere::embedding_model`](super::Cohere::embedding_model).
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct CohereEmbeddingSettings {
+    /// The model to use for embeddings.
+    #[builder(default = "\"embed-v4.0\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"cohere\".to_string()")]
+    pub provider_name: String,
+
+    /// What the embedded texts are used for.
+    #[builder(default)]
+    pub input_type: EmbedInputType,
+
+    /// The maximum number of texts embedded in a single request.
+    #[builder(default = "96", setter(into = false))]
+    pub max_embeddings_per_call: usize,
+}
+
+impl CohereEmbeddingSettings {
+    /// Creates a new builder for `CohereEmbeddingSettings`.
+    pub fn builder() -> CohereEmbeddingSettingsBuilder {
+        CohereEmbeddingSettingsBuilder::default()
+    }
+}
+
+/// What embedded texts are used for, which Cohere's embedding models require.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbedInputType {
+    /// Documents stored in a search index.
+    #[default]
+    SearchDocument,
+    /// Queries run against a search index.
+    SearchQuery,
+    /// Texts passed to a classifier.
+    Classification,
+    /// Texts to be clustered.
+    Clustering,
+}
@@ -0,0 +1,256 @@
+//! This module provides a client for interacting with the Gemini API.
+//! It handles the construction of requests, sending them to the
+//! `generateContent` and `streamGenerateContent` endpoints, and parsing the
+//! responses.
+
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::settings::SafetySetting;
+use crate::core::retry::RetryPolicy;
+use crate::error::{Error, Result};
+use crate::providers::sse;
+
+/// A client for the Gemini API.
+#[derive(Debug, Clone)]
+pub struct GoogleClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl GoogleClient {
+    /// Creates a new `GoogleClient` for the API at the given base URL.
+    pub fn new(api_key: String, base_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            api_key,
+            base_url,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the `generateContent` endpoint of the given model.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        model: &str,
+        request: &GenerateContentRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<GenerateContentResponse> {
+        let url = format!("{}/models/{model}:generateContent", self.base_url);
+        let response = self.send(&url, request, retry_policy).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request to the `streamGenerateContent` endpoint of the given model.
+    ///
+    /// The returned stream yields a partial response for every server-sent event.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        model: &str,
+        request: &GenerateContentRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<GenerateContentResponse>>> {
+        let url = format!(
+            "{}/models/{model}:streamGenerateContent?alt=sse",
+            self.base_url
+        );
+        let response = self.send(&url, request, retry_policy).await?;
+
+        let chunks = sse::data_stream(response).map(|data| Ok(serde_json::from_str(&data?)?));
+
+        Ok(chunks.boxed())
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        url: &str,
+        request: &GenerateContentRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let response = self
+                    .client
+                    .post(url)
+                    .header("x-goog-api-key", &self.api_key)
+                    .json(request)
+                    .send()
+                    .await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// A request to the `generateContent` endpoint.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateContentRequest {
+    pub contents: Vec<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub safety_settings: Vec<SafetySetting>,
+    pub generation_config: GenerationConfig,
+}
+
+/// A message in a conversation, made of one or more parts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub parts: Vec<Part>,
+}
+
+/// A part of a message.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Part {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_call: Option<FunctionCall>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_response: Option<FunctionResponse>,
+    /// Whether the text is a summary of the model's reasoning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thought: Option<bool>,
+}
+
+impl Part {
+    /// Creates a new text part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A call to a function predicted by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+}
+
+/// The result of a function call, sent back to the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub response: serde_json::Value,
+}
+
+/// A set of functions the model may call.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tool {
+    pub function_declarations: Vec<FunctionDeclaration>,
+}
+
+/// The declaration of a function the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDeclaration {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// Configuration options for model generation.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stop_sequences: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_json_schema: Option<serde_json::Value>,
+}
+
+/// A response from the `generateContent` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateContentResponse {
+    #[serde(default)]
+    pub candidates: Vec<Candidate>,
+    pub usage_metadata: Option<UsageMetadata>,
+    pub model_version: Option<String>,
+    pub prompt_feedback: Option<PromptFeedback>,
+}
+
+/// Feedback on the prompt, reported instead of candidates when the prompt
+/// was blocked.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptFeedback {
+    pub block_reason: Option<String>,
+    pub block_reason_message: Option<String>,
+}
+
+/// A response candidate generated by the model.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candidate {
+    pub content: Option<Content>,
+    pub finish_reason: Option<String>,
+}
+
+/// Token usage reported by the Gemini API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageMetadata {
+    #[serde(default)]
+    pub prompt_token_count: u32,
+    #[serde(default)]
+    pub candidates_token_count: u32,
+    #[serde(default)]
+    pub total_token_count: u32,
+    pub cached_content_token_count: Option<u32>,
+    pub thoughts_token_count: Option<u32>,
+}
@@ -0,0 +1,489 @@
+//! This module provides the Google provider, which implements the `LanguageModel`
+//! and `Provider` traits for interacting with the Gemini API.
+
+pub mod client;
+pub mod settings;
+
+pub use settings::{GoogleProviderSettings, SafetySetting};
+
+use self::client::{
+    Content, FunctionCall, FunctionDeclaration, FunctionResponse, GenerateContentRequest,
+    GenerateContentResponse, GenerationConfig, GoogleClient, Part, Tool,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
+    },
+    error::{ApiErrorDetails, Error, Result},
+    providers::{tool_call_count, user_text},
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// The Google provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Google {
+    #[serde(skip)]
+    client: GoogleClient,
+    settings: GoogleProviderSettings,
+}
+
+impl Google {
+    /// Creates a new `Google` provider with the given settings.
+    pub fn new(settings: GoogleProviderSettings) -> Self {
+        let client = GoogleClient::new(settings.api_key.to_string(), settings.base_url.to_string())
+            .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Builds a `generateContent` request from the provider-neutral call options.
+    fn content_request(&self, options: LanguageModelCallOptions) -> Result<GenerateContentRequest> {
+        let declarations = options
+            .tools
+            .into_iter()
+            .map(|tool| FunctionDeclaration {
+                name: tool.name,
+                description: tool.description,
+                parameters: tool.parameters,
+            })
+            .collect::<Vec<_>>();
+        let (response_mime_type, response_json_schema) = match options.response_format {
+            Some(ResponseFormat::Json { schema, .. }) => {
+                (Some("application/json".to_string()), schema)
+            }
+            Some(ResponseFormat::Text) | None => (None, None),
+        };
+        let (system_instruction, contents) = to_google_contents(options.system, options.messages)?;
+
+        Ok(GenerateContentRequest {
+            contents,
+            system_instruction,
+            tools: (!declarations.is_empty()).then(|| {
+                vec![Tool {
+                    function_declarations: declarations,
+                }]
+            }),
+            safety_settings: self.settings.safety_settings.clone(),
+            generation_config: GenerationConfig {
+                temperature: options.settings.temperature,
+                top_p: options.settings.top_p,
+                top_k: options.settings.top_k,
+                presence_penalty: options.settings.presence_penalty,
+                frequency_penalty: options.settings.frequency_penalty,
+                stop_sequences: options.settings.stop_sequences,
+                seed: options.settings.seed,
+                max_output_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+                response_mime_type,
+                response_json_schema,
+            },
+        })
+    }
+}
+
+impl Provider for Google {}
+
+#[async_trait]
+impl LanguageModel for Google {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let first_tool_call = tool_call_count(&options.messages);
+        let request = self.content_request(options)?;
+
+        let response = self
+            .client
+            .generate_text(self.model_name(), &request, retry_policy.as_ref())
+            .await?;
+        if let Some(error) = blocked_prompt_error(&response) {
+            return Err(error);
+        }
+        let usage = response.usage_metadata.as_ref().map(to_usage);
+        let candidate = response
+            .candidates
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("response contained no candidates".to_string()))?;
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for part in candidate
+            .content
+            .map(|content| content.parts)
+            .unwrap_or_default()
+        {
+            if part.thought == Some(true) {
+                continue;
+            }
+            if let Some(part_text) = part.text {
+                text.push_str(&part_text);
+            }
+            if let Some(call) = part.function_call {
+                tool_calls.push(to_tool_call(call, first_tool_call + tool_calls.len()));
+            }
+        }
+
+        let finish_reason = match to_finish_reason(candidate.finish_reason.as_deref()) {
+            FinishReason::Stop if !tool_calls.is_empty() => FinishReason::ToolCalls,
+            reason => reason,
+        };
+
+        Ok(LanguageModelResponse {
+            text,
+            model: response.model_version,
+            tool_calls,
+            finish_reason,
+            usage,
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let mut state = StreamState {
+            first_tool_call: tool_call_count(&options.messages),
+            ..Default::default()
+        };
+        let request = self.content_request(options)?;
+
+        let responses = self
+            .client
+            .stream_text(self.model_name(), &request, retry_policy.as_ref())
+            .await?;
+
+        Ok(responses
+            .map(move |response| match response {
+                Ok(response) => state.process(response),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts a Gemini finish reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("STOP") => FinishReason::Stop,
+        Some("MAX_TOKENS") => FinishReason::Length,
+        Some(
+            "SAFETY" | "RECITATION" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII" | "IMAGE_SAFETY",
+        ) => FinishReason::ContentFilter,
+        Some("MALFORMED_FUNCTION_CALL") => FinishReason::Error,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Returns a content filter error when the response has no candidates
+/// because the prompt was blocked.
+fn blocked_prompt_error(response: &GenerateContentResponse) -> Option<Error> {
+    if !response.candidates.is_empty() {
+        return None;
+    }
+    let feedback = response.prompt_feedback.as_ref()?;
+    let reason = feedback.block_reason.clone()?;
+
+    Some(Error::ContentFilterError(ApiErrorDetails {
+        message: feedback
+            .block_reason_message
+            .clone()
+            .unwrap_or_else(|| format!("the prompt was blocked: {reason}")),
+        code: Some(reason),
+        ..Default::default()
+    }))
+}
+
+/// Converts Gemini usage metadata into normalized token usage.
+fn to_usage(usage: &client::UsageMetadata) -> Usage {
+    let reasoning_tokens = usage.thoughts_token_count;
+    Usage {
+        prompt_tokens: usage.prompt_token_count,
+        completion_tokens: usage.candidates_token_count + reasoning_tokens.unwrap_or(0),
+        total_tokens: usage.total_token_count,
+        cached_tokens: usage.cached_content_token_count,
+        reasoning_tokens,
+    }
+}
+
+/// Converts a Gemini function call into a tool call. Gemini does not always
+/// assign IDs to function calls, so one is derived from the call's position
+/// in the conversation when it is missing.
+fn to_tool_call(call: FunctionCall, index: usize) -> ToolCall {
+    ToolCall {
+        id: call.id.unwrap_or_else(|| format!("call_{index}")),
+        name: call.name,
+        arguments: call.args,
+    }
+}
+
+/// Converts streamed Gemini responses into provider-neutral stream chunks.
+///
+/// Every streamed response carries complete function calls, and the usage is
+/// cumulative, so it is only reported with the final response.
+#[derive(Debug, Default)]
+struct StreamState {
+    first_tool_call: usize,
+    tool_calls: usize,
+}
+
+impl StreamState {
+    fn process(&mut self, response: GenerateContentResponse) -> Vec<LanguageModelStreamChunk> {
+        let mut chunks = Vec::new();
+        if let Some(error) = blocked_prompt_error(&response) {
+            chunks.push(LanguageModelStreamChunk::Error(error.to_string()));
+            return chunks;
+        }
+        let Some(candidate) = response.candidates.into_iter().next() else {
+            return chunks;
+        };
+
+        for part in candidate
+            .content
+            .map(|content| content.parts)
+            .unwrap_or_default()
+        {
+            if part.thought == Some(true) {
+                continue;
+            }
+            if let Some(text) = part.text.filter(|text| !text.is_empty()) {
+                chunks.push(LanguageModelStreamChunk::TextDelta(text));
+            }
+            if let Some(call) = part.function_call {
+                chunks.push(LanguageModelStreamChunk::ToolCall(to_tool_call(
+                    call,
+                    self.first_tool_call + self.tool_calls,
+                )));
+                self.tool_calls += 1;
+            }
+        }
+
+        if let Some(reason) = candidate.finish_reason {
+            let finish_reason = match to_finish_reason(Some(&reason)) {
+                FinishReason::Stop if self.tool_calls > 0 => FinishReason::ToolCalls,
+                reason => reason,
+            };
+            chunks.push(LanguageModelStreamChunk::Finish { finish_reason });
+            if let Some(usage) = &response.usage_metadata {
+                chunks.push(LanguageModelStreamChunk::Usage(to_usage(usage)));
+            }
+        }
+
+        chunks
+    }
+}
+
+/// Converts the provider-neutral conversation into Gemini contents.
+///
+/// Gemini only accepts a top-level system instruction, so system messages are
+/// appended to it. Assistant messages use the `model` role, tool results are
+/// sent as user function responses named after the matching call, and
+/// consecutive contents with the same role are merged.
+fn to_google_contents(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<(Option<Content>, Vec<Content>)> {
+    let mut system_parts: Vec<String> = system.into_iter().collect();
+    let mut tool_names: HashMap<String, String> = HashMap::new();
+    let mut result: Vec<Content> = Vec::new();
+
+    for message in messages {
+        let (role, parts) = match message {
+            types::Message::System { content } => {
+                system_parts.push(content);
+                continue;
+            }
+            types::Message::User { content } => {
+                ("user", vec![Part::text(user_text("Google", content)?)])
+            }
+            types::Message::Assistant {
+                content,
+                tool_calls,
+            } => {
+                let text = (!content.is_empty()).then(|| Part::text(content));
+                let calls = tool_calls.into_iter().map(|call| {
+                    tool_names.insert(call.id.clone(), call.name.clone());
+                    Part {
+                        function_call: Some(FunctionCall {
+                            id: None,
+                            name: call.name,
+                            args: call.arguments,
+                        }),
+                        ..Part::default()
+                    }
+                });
+                ("model", text.into_iter().chain(calls).collect())
+            }
+            types::Message::Tool {
+                tool_call_id,
+                content,
+                ..
+            } => {
+                let name = tool_names
+                    .get(&tool_call_id)
+                    .cloned()
+                    .unwrap_or(tool_call_id);
+                let response = match serde_json::from_str(&content) {
+                    Ok(Value::Object(object)) => Value::Object(object),
+                    Ok(value) => json!({ "result": value }),
+                    Err(_) => json!({ "result": content }),
+                };
+                let part = Part {
+                    function_response: Some(FunctionResponse {
+                        id: None,
+                        name,
+                        response,
+                    }),
+                    ..Part::default()
+                };
+                ("user", vec![part])
+            }
+        };
+
+        match result.last_mut() {
+            Some(last) if last.role.as_deref() == Some(role) => last.parts.extend(parts),
+            _ => result.push(Content {
+                role: Some(role.to_string()),
+                parts,
+            }),
+        }
+    }
+
+    let system = (!system_parts.is_empty()).then(|| Content {
+        role: None,
+        parts: vec![Part::text(system_parts.join("\n\n"))],
+    });
+    Ok((system, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_google_contents_maps_roles_and_function_responses() {
+        let (system, contents) = to_google_contents(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::system("use metric units"),
+                types::Message::user("weather in Paris and Rome?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "call_0".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("call_0", r#"{"celsius": 21}"#),
+                types::Message::tool("call_1", "25"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            system.unwrap().parts,
+            [Part::text("be brief\n\nuse metric units")]
+        );
+        let roles: Vec<_> = contents.iter().map(|c| c.role.as_deref()).collect();
+        assert_eq!(roles, [Some("user"), Some("model"), Some("user")]);
+
+        let responses: Vec<_> = contents[2]
+            .parts
+            .iter()
+            .map(|part| part.function_response.clone().unwrap())
+            .collect();
+        assert_eq!(responses[0].name, "get_weather");
+        assert_eq!(responses[0].response, json!({"celsius": 21}));
+        assert_eq!(responses[1].name, "call_1");
+        assert_eq!(responses[1].response, json!({"result": 25}));
+    }
+
+    #[test]
+    fn test_stream_state_reports_blocked_prompts() {
+        let response = r#"{"promptFeedback":{"blockReason":"SAFETY"}}"#;
+
+        let chunks = StreamState::default().process(serde_json::from_str(response).unwrap());
+
+        let [LanguageModelStreamChunk::Error(message)] = chunks.as_slice() else {
+            panic!("expected a single error chunk");
+        };
+        assert!(message.contains("SAFETY"));
+    }
+
+    #[test]
+    fn test_stream_state_reports_tool_calls_and_final_usage() {
+        let responses = [
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"text":"Checking"}]}}],"usageMetadata":{"promptTokenCount":10}}"#,
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{"name":"get_weather","args":{"city":"Paris"}}}]},"finishReason":"STOP"}],"usageMetadata":{"promptTokenCount":10,"candidatesTokenCount":20,"totalTokenCount":30}}"#,
+        ];
+
+        let mut state = StreamState::default();
+        let chunks: Vec<_> = responses
+            .iter()
+            .flat_map(|response| state.process(serde_json::from_str(response).unwrap()))
+            .collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                LanguageModelStreamChunk::TextDelta("Checking".to_string()),
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "call_0".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+                LanguageModelStreamChunk::Usage(Usage {
+                    prompt_tokens: 10,
+                    completion_tokens: 20,
+                    total_tokens: 30,
+                    cached_tokens: None,
+                    reasoning_tokens: None,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_state_numbers_tool_calls_after_earlier_steps() {
+        let response = r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{"name":"get_weather","args":{"city":"Rome"}}}]},"finishReason":"STOP"}]}"#;
+        let messages = [types::Message::assistant_with_tool_calls(
+            "",
+            vec![ToolCall {
+                id: "call_0".to_string(),
+                name: "get_weather".to_string(),
+                arguments: json!({"city": "Paris"}),
+            }],
+        )];
+
+        let mut state = StreamState {
+            first_tool_call: tool_call_count(&messages),
+            ..Default::default()
+        };
+        let chunks = state.process(serde_json::from_str(response).unwrap());
+
+        assert!(matches!(
+            &chunks[0],
+            LanguageModelStreamChunk::ToolCall(call) if call.id == "call_1"
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+//! Defines the settings for the Google Gemini provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Google Gemini provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct GoogleProviderSettings {
+    /// The API key for the Gemini API.
+    #[builder(default = "std::env::var(\"GEMINI_API_KEY\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// The model to use for text generation.
+    #[builder(default = "\"gemini-2.5-flash\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"google\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the Gemini API.
+    #[builder(default = "\"https://generativelanguage.googleapis.com/v1beta\".to_string()")]
+    pub base_url: String,
+
+    /// The maximum number of tokens to generate. Uses the model's limit when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// The safety settings applied to every request.
+    #[builder(default)]
+    pub safety_settings: Vec<SafetySetting>,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl GoogleProviderSettings {
+    /// Creates a new builder for `GoogleProviderSettings`.
+    pub fn builder() -> GoogleProviderSettingsBuilder {
+        GoogleProviderSettingsBuilder::default()
+    }
+}
+
+/// A safety setting that controls how content of a harm category is blocked.
+///
+/// See the Gemini API documentation for the available categories (e.g.
+/// `HARM_CATEGORY_HARASSMENT`) and thresholds (e.g. `BLOCK_ONLY_HIGH`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafetySetting {
+    /// The harm category the setting applies to.
+    pub category: String,
+
+    /// The probability threshold at which content is blocked.
+    pub threshold: String,
+}
+
+impl SafetySetting {
+    /// Creates a new safety setting for the given category and threshold.
+    pub fn new(category: impl Into<String>, threshold: impl Into<String>) -> Self {
+        Self {
+            category: category.into(),
+            threshold: threshold.into(),
+        }
+    }
+}
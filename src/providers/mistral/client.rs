@@ -0,0 +1,284 @@
+//! This module provides a client for interacting with the Mistral API.
+//! It handles the construction of requests, sending them to the chat
+//! completions endpoint, and parsing the responses.
+
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::{Error, Result};
+use crate::providers::sse;
+
+/// A client for the Mistral API.
+#[derive(Debug, Clone)]
+pub struct MistralClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl MistralClient {
+    /// Creates a new `MistralClient` for the API at the given base URL.
+    pub fn new(api_key: String, base_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            api_key,
+            base_url,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the Mistral API to generate text.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<ChatResponse> {
+        let response = self.send(request, retry_policy).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a streaming request to the Mistral API to generate text.
+    ///
+    /// The returned stream yields the parsed server-sent events until the
+    /// API signals the end of the stream.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<ChatChunk>>> {
+        let response = self.send(request, retry_policy).await?;
+
+        let chunks = sse::data_stream(response)
+            .filter(|data| futures::future::ready(!matches!(data, Ok(data) if data == "[DONE]")))
+            .map(|data| Ok(serde_json::from_str(&data?)?));
+
+        Ok(chunks.boxed())
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let response = self
+                    .client
+                    .post(format!("{}/chat/completions", self.base_url))
+                    .bearer_auth(&self.api_key)
+                    .json(request)
+                    .send()
+                    .await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// A request to the chat completions endpoint.
+#[derive(Debug, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub random_seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+    pub safe_prompt: bool,
+    pub stream: bool,
+}
+
+/// The format the model must respond in.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+/// A JSON Schema the model's response must match.
+#[derive(Debug, Serialize)]
+pub struct JsonSchemaFormat {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub schema: serde_json::Value,
+    pub strict: bool,
+}
+
+/// A message in a chat conversation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Message {
+    pub role: String,
+    #[serde(default)]
+    pub content: Option<Content>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+    /// The name of the tool whose result the message contains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The content of a message, which reasoning models send as a list of chunks.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Content {
+    Text(String),
+    Chunks(Vec<ContentChunk>),
+}
+
+impl Content {
+    /// Returns the text of the content, leaving out reasoning chunks.
+    pub fn text(self) -> String {
+        match self {
+            Content::Text(text) => text,
+            Content::Chunks(chunks) => chunks
+                .into_iter()
+                .filter_map(|chunk| match chunk {
+                    ContentChunk::Text { text } => Some(text),
+                    ContentChunk::Unknown => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A chunk of message content.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentChunk {
+    Text {
+        text: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A tool that the model may call.
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionDefinition,
+}
+
+/// The definition of a function that the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool call generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "function_kind")]
+    pub kind: String,
+    pub function: FunctionCall,
+}
+
+fn function_kind() -> String {
+    "function".to_string()
+}
+
+/// The function that the model called.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments to call the function with. Mistral sends them as a JSON
+    /// encoded string, but also accepts an object.
+    pub arguments: serde_json::Value,
+}
+
+/// A response from the chat completions endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ChatResponse {
+    pub id: String,
+    pub model: String,
+    pub choices: Vec<ChatChoice>,
+    pub usage: Option<UsageInfo>,
+}
+
+/// A choice in a chat completion response.
+#[derive(Debug, Deserialize)]
+pub struct ChatChoice {
+    pub index: u32,
+    pub message: Message,
+    pub finish_reason: Option<String>,
+}
+
+/// Token usage reported by the Mistral API.
+#[derive(Debug, Deserialize)]
+pub struct UsageInfo {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// A chunk of a streamed chat completion response.
+#[derive(Debug, Deserialize)]
+pub struct ChatChunk {
+    pub id: String,
+    pub model: String,
+    pub choices: Vec<ChatChunkChoice>,
+    pub usage: Option<UsageInfo>,
+}
+
+/// A choice in a streamed chat completion chunk.
+#[derive(Debug, Deserialize)]
+pub struct ChatChunkChoice {
+    pub index: u32,
+    pub delta: ChatDelta,
+    pub finish_reason: Option<String>,
+}
+
+/// The incremental message content of a streamed choice. Unlike OpenAI,
+/// Mistral sends every tool call complete in a single delta.
+#[derive(Debug, Deserialize)]
+pub struct ChatDelta {
+    pub role: Option<String>,
+    pub content: Option<Content>,
+    pub tool_calls: Option<Vec<ToolCall>>,
+}
@@ -0,0 +1,418 @@
+//! This module provides the Mistral provider, which implements the `LanguageModel`
+//! and `Provider` traits for interacting with the Mistral API.
+
+pub mod client;
+pub mod settings;
+
+pub use settings::MistralProviderSettings;
+
+use self::client::{
+    ChatChunk, ChatRequest, Content, FunctionCall, FunctionDefinition, JsonSchemaFormat, Message,
+    MistralClient, Tool, UsageInfo,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
+    },
+    error::{Error, Result},
+    providers::{parse_tool_arguments, strict_json_schema, user_text},
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The Mistral provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Mistral {
+    #[serde(skip)]
+    client: MistralClient,
+    settings: MistralProviderSettings,
+}
+
+impl Mistral {
+    /// Creates a new `Mistral` provider with the given settings.
+    pub fn new(settings: MistralProviderSettings) -> Self {
+        let client =
+            MistralClient::new(settings.api_key.to_string(), settings.base_url.to_string())
+                .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Builds a chat request from the provider-neutral call options.
+    fn chat_request(&self, options: LanguageModelCallOptions, stream: bool) -> Result<ChatRequest> {
+        if options.settings.top_k.is_some() {
+            log::warn!("Mistral does not support `top_k`, ignoring it");
+        }
+
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                kind: "function".to_string(),
+                function: FunctionDefinition {
+                    name: tool.name,
+                    description: tool.description,
+                    parameters: tool.parameters,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ChatRequest {
+            model: self.model_name().to_string(),
+            messages: to_mistral_messages(options.system, options.messages)?,
+            max_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+            temperature: options.settings.temperature,
+            top_p: options.settings.top_p,
+            presence_penalty: options.settings.presence_penalty,
+            frequency_penalty: options.settings.frequency_penalty,
+            stop: (!options.settings.stop_sequences.is_empty())
+                .then_some(options.settings.stop_sequences),
+            random_seed: options.settings.seed,
+            tools: (!tools.is_empty()).then_some(tools),
+            response_format: options.response_format.map(to_mistral_response_format),
+            safe_prompt: self.settings.safe_prompt,
+            stream,
+        })
+    }
+}
+
+impl Provider for Mistral {}
+
+#[async_trait]
+impl LanguageModel for Mistral {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, false)?;
+
+        let response = self
+            .client
+            .generate_text(&request, retry_policy.as_ref())
+            .await?;
+        let choice = response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("response contained no choices".to_string()))?;
+        let message = choice.message;
+
+        let tool_calls = message
+            .tool_calls
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_tool_call)
+            .collect();
+
+        Ok(LanguageModelResponse {
+            text: message.content.map(Content::text).unwrap_or_default(),
+            model: Some(response.model),
+            tool_calls,
+            finish_reason: to_finish_reason(choice.finish_reason.as_deref()),
+            usage: response.usage.map(to_usage),
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, true)?;
+
+        let chunks = self
+            .client
+            .stream_text(&request, retry_policy.as_ref())
+            .await?;
+
+        Ok(chunks
+            .map(|chunk| match chunk {
+                Ok(chunk) => process_chunk(chunk),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts the provider-neutral response format into a Mistral response format.
+fn to_mistral_response_format(format: ResponseFormat) -> client::ResponseFormat {
+    match format {
+        ResponseFormat::Text => client::ResponseFormat::Text,
+        ResponseFormat::Json { schema: None, .. } => client::ResponseFormat::JsonObject,
+        ResponseFormat::Json {
+            schema: Some(schema),
+            name,
+            description,
+        } => client::ResponseFormat::JsonSchema {
+            json_schema: JsonSchemaFormat {
+                name: name.unwrap_or_else(|| "response".to_string()),
+                description,
+                schema: strict_json_schema(schema),
+                strict: true,
+            },
+        },
+    }
+}
+
+/// Converts a Mistral finish reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("stop") => FinishReason::Stop,
+        Some("length" | "model_length") => FinishReason::Length,
+        Some("tool_calls") => FinishReason::ToolCalls,
+        Some("error") => FinishReason::Error,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts Mistral token usage into normalized token usage.
+fn to_usage(usage: UsageInfo) -> Usage {
+    Usage {
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+        ..Default::default()
+    }
+}
+
+/// Converts a Mistral tool call into a tool call. The arguments are usually
+/// a JSON encoded string, but a JSON object is accepted as well.
+fn to_tool_call(call: client::ToolCall) -> ToolCall {
+    let arguments = match call.function.arguments {
+        Value::String(arguments) => parse_tool_arguments(&arguments),
+        arguments => arguments,
+    };
+    ToolCall {
+        id: call.id,
+        name: call.function.name,
+        arguments,
+    }
+}
+
+/// Converts a streamed Mistral chunk into provider-neutral stream chunks.
+/// Mistral streams every tool call in one piece, so no state is needed.
+fn process_chunk(chunk: ChatChunk) -> Vec<LanguageModelStreamChunk> {
+    let mut chunks = Vec::new();
+    for choice in chunk.choices {
+        if let Some(content) = choice.delta.content.map(Content::text)
+            && !content.is_empty()
+        {
+            chunks.push(LanguageModelStreamChunk::TextDelta(content));
+        }
+        for call in choice.delta.tool_calls.unwrap_or_default() {
+            chunks.push(LanguageModelStreamChunk::ToolCall(to_tool_call(call)));
+        }
+        if let Some(finish_reason) = choice.finish_reason {
+            chunks.push(LanguageModelStreamChunk::Finish {
+                finish_reason: to_finish_reason(Some(&finish_reason)),
+            });
+        }
+    }
+    if let Some(usage) = chunk.usage {
+        chunks.push(LanguageModelStreamChunk::Usage(to_usage(usage)));
+    }
+    chunks
+}
+
+/// Converts a tool call ID into one Mistral accepts, which must be nine
+/// alphanumeric characters. IDs generated by Mistral are kept as they are, so
+/// only conversations started with other providers are affected. Other IDs
+/// are replaced by a 64-bit FNV-1a hash of the whole ID, encoded in base 62,
+/// so that distinct IDs stay distinct and a call keeps the same ID across
+/// requests.
+fn to_mistral_tool_call_id(id: &str) -> String {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+    if id.len() == 9 && id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return id.to_string();
+    }
+
+    let mut hash = id.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    let mut encoded = [0u8; 9];
+    for digit in encoded.iter_mut().rev() {
+        *digit = ALPHABET[(hash % 62) as usize];
+        hash /= 62;
+    }
+    String::from_utf8_lossy(&encoded).into_owned()
+}
+
+/// Converts the provider-neutral conversation into Mistral chat messages,
+/// placing the system prompt first. Tool results are named after the tool of
+/// the matching call.
+fn to_mistral_messages(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<Vec<Message>> {
+    let mut tool_names: HashMap<String, String> = HashMap::new();
+    let message = |role: &str, content: Option<String>| Message {
+        role: role.to_string(),
+        content: content.map(Content::Text),
+        tool_calls: None,
+        tool_call_id: None,
+        name: None,
+    };
+
+    let system = system.map(types::Message::system);
+    system
+        .into_iter()
+        .chain(messages)
+        .map(|msg| {
+            Ok(match msg {
+                types::Message::System { content } => message("system", Some(content)),
+                types::Message::User { content } => {
+                    message("user", Some(user_text("Mistral", content)?))
+                }
+                types::Message::Assistant {
+                    content,
+                    tool_calls,
+                } if !tool_calls.is_empty() => {
+                    let tool_calls = tool_calls
+                        .into_iter()
+                        .map(|call| {
+                            tool_names.insert(call.id.clone(), call.name.clone());
+                            client::ToolCall {
+                                id: to_mistral_tool_call_id(&call.id),
+                                kind: "function".to_string(),
+                                function: FunctionCall {
+                                    name: call.name,
+                                    arguments: Value::String(call.arguments.to_string()),
+                                },
+                            }
+                        })
+                        .collect();
+                    Message {
+                        tool_calls: Some(tool_calls),
+                        ..message("assistant", (!content.is_empty()).then_some(content))
+                    }
+                }
+                types::Message::Assistant { content, .. } => message("assistant", Some(content)),
+                types::Message::Tool {
+                    tool_call_id,
+                    content,
+                    ..
+                } => Message {
+                    name: tool_names.get(&tool_call_id).cloned(),
+                    tool_call_id: Some(to_mistral_tool_call_id(&tool_call_id)),
+                    ..message("tool", Some(content))
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_mistral_tool_call_id() {
+        assert_eq!(to_mistral_tool_call_id("D681PevKs"), "D681PevKs");
+
+        let id = to_mistral_tool_call_id("toolu_01A09q90qw90lq917835lq9");
+        assert_eq!(id.len(), 9);
+        assert!(id.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_eq!(id, to_mistral_tool_call_id("toolu_01A09q90qw90lq917835lq9"));
+    }
+
+    #[test]
+    fn test_to_mistral_tool_call_id_keeps_distinct_ids_distinct() {
+        let ids = [
+            "call_0",
+            "call-0",
+            "toolu_01A09q90qw90lq917835lq9",
+            "toolu_02A09q90qw90lq917835lq9",
+            "call_abc123456789",
+            "call_xyz123456789",
+        ];
+
+        let converted: std::collections::HashSet<_> =
+            ids.iter().map(|id| to_mistral_tool_call_id(id)).collect();
+
+        assert_eq!(converted.len(), ids.len());
+    }
+
+    #[test]
+    fn test_to_mistral_messages_encodes_tool_calls() {
+        let messages = to_mistral_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::user("weather in Paris?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "call_0".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("call_0", "21"),
+            ],
+        )
+        .unwrap();
+
+        let id = to_mistral_tool_call_id("call_0");
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            json!([
+                {"role": "system", "content": "be brief"},
+                {"role": "user", "content": "weather in Paris?"},
+                {"role": "assistant", "content": null, "tool_calls": [{
+                    "id": id,
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]},
+                {"role": "tool", "content": "21", "tool_call_id": id, "name": "get_weather"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_emits_complete_tool_calls() {
+        let chunk: ChatChunk = serde_json::from_str(
+            r#"{"id":"1","model":"mistral-large-latest",
+                "choices":[{"index":0,"delta":{"tool_calls":[{"id":"D681PevKs","function":
+                    {"name":"get_weather","arguments":"{\"city\": \"Paris\"}"}}]},
+                "finish_reason":"tool_calls"}],
+                "usage":{"prompt_tokens":10,"completion_tokens":20,"total_tokens":30}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            process_chunk(chunk),
+            vec![
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "D681PevKs".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+                LanguageModelStreamChunk::Usage(Usage {
+                    prompt_tokens: 10,
+                    completion_tokens: 20,
+                    total_tokens: 30,
+                    ..Default::default()
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Defines the settings for the Mistral provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Mistral provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct MistralProviderSettings {
+    /// The API key for the Mistral API.
+    #[builder(default = "std::env::var(\"MISTRAL_API_KEY\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// The model to use for text generation.
+    #[builder(default = "\"mistral-large-latest\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"mistral\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the Mistral API.
+    #[builder(default = "\"https://api.mistral.ai/v1\".to_string()")]
+    pub base_url: String,
+
+    /// The maximum number of tokens to generate. Uses the model's limit when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// Whether to inject Mistral's safety prompt before the conversation.
+    #[builder(default)]
+    pub safe_prompt: bool,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl MistralProviderSettings {
+    /// Creates a new builder for `MistralProviderSettings`.
+    pub fn builder() -> MistralProviderSettingsBuilder {
+        MistralProviderSettingsBuilder::default()
+    }
+}
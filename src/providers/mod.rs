@@ -1,5 +1,188 @@
 //! This module provides the `Provider` trait, which defines the interface for
 //! interacting with different AI providers.
 
+pub mod sse;
+
+#[cfg(feature = "anthropic")]
+pub mod anthropic;
+
+#[cfg(feature = "azure")]
+pub mod azure;
+
+#[cfg(feature = "bedrock")]
+pub mod bedrock;
+
+#[cfg(feature = "cohere")]
+pub mod cohere;
+
+#[cfg(feature = "google")]
+pub mod google;
+
+#[cfg(feature = "mistral")]
+pub mod mistral;
+
+#[cfg(feature = "ollama")]
+pub mod ollama;
+
 #[cfg(feature = "openai")]
 pub mod openai;
+
+#[cfg(feature = "tgi")]
+pub mod tgi;
+
+/// Joins the text parts of a user message for providers whose converters only
+/// handle text so far.
+///
+/// # Errors
+///
+/// Returns an `Error::UnsupportedContentError` if the message contains any
+/// other part.
+#[cfg(any(
+    feature = "anthropic",
+    feature = "bedrock",
+    feature = "cohere",
+    feature = "google",
+    feature = "mistral",
+    feature = "ollama",
+    feature = "tgi"
+))]
+pub(crate) fn user_text(
+    provider: &str,
+    content: Vec<crate::core::types::ContentPart>,
+) -> crate::error::Result<String> {
+    use crate::core::types::ContentPart;
+    use crate::error::Error;
+
+    let mut texts = Vec::new();
+    for part in content {
+        let kind = match part {
+            ContentPart::Text { text } => {
+                texts.push(text);
+                continue;
+            }
+            ContentPart::Image { .. } => "image",
+            ContentPart::Audio { .. } => "audio",
+            ContentPart::File { .. } => "file",
+        };
+        return Err(Error::UnsupportedContentError(format!(
+            "{provider} provider does not yet support {kind} content parts in this SDK"
+        )));
+    }
+    Ok(texts.join("\n"))
+}
+
+/// Rewrites a JSON Schema so that it can be sent with `strict: true`, which
+/// requires every object to list all of its properties as required and to
+/// forbid additional properties. Properties that were optional become
+/// nullable instead.
+#[cfg(any(feature = "openai", feature = "mistral"))]
+pub(crate) fn strict_json_schema(mut schema: serde_json::Value) -> serde_json::Value {
+    make_strict(&mut schema);
+    schema
+}
+
+#[cfg(any(feature = "openai", feature = "mistral"))]
+fn make_strict(schema: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    for key in ["$defs", "definitions", "properties"] {
+        if let Some(Value::Object(schemas)) = object.get_mut(key) {
+            schemas.values_mut().for_each(make_strict);
+        }
+    }
+    for key in ["anyOf", "oneOf", "allOf", "prefixItems"] {
+        if let Some(Value::Array(schemas)) = object.get_mut(key) {
+            schemas.iter_mut().for_each(make_strict);
+        }
+    }
+    for key in ["items", "additionalProperties"] {
+        if let Some(schema @ Value::Object(_)) = object.get_mut(key) {
+            make_strict(schema);
+        }
+    }
+
+    let is_object = object.contains_key("properties")
+        || object.get("type").and_then(Value::as_str) == Some("object");
+    if !is_object {
+        return;
+    }
+    if !matches!(object.get("additionalProperties"), Some(Value::Object(_))) {
+        object.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+
+    let required: Vec<Value> = match object.get("required") {
+        Some(Value::Array(required)) => required.clone(),
+        _ => Vec::new(),
+    };
+    let Some(Value::Object(properties)) = object.get_mut("properties") else {
+        return;
+    };
+    for (name, property) in properties.iter_mut() {
+        if !required.iter().any(|required| required == name) {
+            *property = nullable(std::mem::take(property));
+        }
+    }
+    let names = properties.keys().cloned().map(Value::String).collect();
+    object.insert("required".to_string(), Value::Array(names));
+}
+
+/// Allows `null` in addition to the values a schema accepts.
+#[cfg(any(feature = "openai", feature = "mistral"))]
+fn nullable(mut schema: serde_json::Value) -> serde_json::Value {
+    use serde_json::{Value, json};
+
+    let null_type = Value::String("null".to_string());
+    let constrained = schema.get("enum").is_some() || schema.get("const").is_some();
+    match schema.get_mut("type") {
+        Some(Value::Array(types)) if !constrained => {
+            if !types.contains(&null_type) {
+                types.push(null_type);
+            }
+            schema
+        }
+        Some(kind @ Value::String(_)) if !constrained => {
+            if *kind != null_type {
+                *kind = Value::Array(vec![kind.clone(), null_type]);
+            }
+            schema
+        }
+        _ => json!({"anyOf": [schema, {"type": "null"}]}),
+    }
+}
+
+/// Parses the JSON encoded arguments of a tool call. Empty arguments are
+/// treated as an empty object, and arguments that are not valid JSON are kept
+/// as a string, so that the failure can be reported back to the model.
+#[cfg(any(
+    feature = "cohere",
+    feature = "mistral",
+    feature = "openai",
+    feature = "tgi"
+))]
+pub(crate) fn parse_tool_arguments(arguments: &str) -> serde_json::Value {
+    if arguments.trim().is_empty() {
+        return serde_json::Value::Object(Default::default());
+    }
+    serde_json::from_str(arguments)
+        .unwrap_or_else(|_| serde_json::Value::String(arguments.to_string()))
+}
+
+/// Counts the tool calls made so far in a conversation. Providers that do not
+/// assign IDs to tool calls number new calls from this count, so that the IDs
+/// stay unique across the steps of a multi-step conversation.
+#[cfg(any(feature = "google", feature = "ollama"))]
+pub(crate) fn tool_call_count(messages: &[crate::core::types::Message]) -> usize {
+    use crate::core::types::Message;
+
+    messages
+        .iter()
+        .map(|message| match message {
+            Message::Assistant { tool_calls, .. } => tool_calls.len(),
+            _ => 0,
+        })
+        .sum()
+}
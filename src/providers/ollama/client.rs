@@ -0,0 +1,273 @@
+//! This module provides a client for interacting with a local Ollama server.
+//! It handles the construction of requests, sending them to the `/api/chat`
+//! and `/api/tags` endpoints, and parsing the responses.
+
+use futures::{StreamExt, stream, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::settings::ModelOptions;
+use crate::core::retry::RetryPolicy;
+use crate::error::{ApiErrorDetails, Error, Result};
+
+/// A client for the Ollama API.
+#[derive(Debug, Clone)]
+pub struct OllamaClient {
+    client: Client,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl OllamaClient {
+    /// Creates a new `OllamaClient` for the server at the given base URL.
+    pub fn new(base_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            base_url,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a chat request to the Ollama server.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<ChatResponse> {
+        let response = self.send(request, retry_policy).await?;
+        parse_response(&response.text().await?)
+    }
+
+    /// Sends a streaming chat request to the Ollama server.
+    ///
+    /// Ollama streams newline-delimited JSON, and the returned stream yields
+    /// one partial response per line.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<ChatResponse>>> {
+        let response = self.send(request, retry_policy).await?;
+
+        let mut buffer = LineBuffer::default();
+        let lines = response
+            .bytes_stream()
+            .map(move |bytes| match bytes {
+                Ok(bytes) => buffer.feed(&bytes).into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(Error::from(err))],
+            })
+            .flat_map(stream::iter);
+
+        Ok(lines
+            .map(|line| line.and_then(|line| parse_response(&line)))
+            .boxed())
+    }
+
+    /// Lists the models installed on the Ollama server.
+    pub async fn list_models(&self) -> Result<ListModelsResponse> {
+        let response = self
+            .client
+            .get(format!("{}/api/tags", self.base_url))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Error::from_response(response).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let response = self
+                    .client
+                    .post(format!("{}/api/chat", self.base_url))
+                    .json(request)
+                    .send()
+                    .await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// Parses a chat response, which Ollama replaces with an error object when a
+/// request fails, even in the middle of a stream.
+fn parse_response(text: &str) -> Result<ChatResponse> {
+    #[derive(Deserialize)]
+    struct ErrorResponse {
+        error: String,
+    }
+
+    if let Ok(error) = serde_json::from_str::<ErrorResponse>(text) {
+        let details = ApiErrorDetails {
+            message: error.error,
+            ..Default::default()
+        };
+        return Err(Error::from_api_error(details, None));
+    }
+    Ok(serde_json::from_str(text)?)
+}
+
+/// Splits bytes received from the network into complete, non-empty lines.
+#[derive(Debug, Default)]
+struct LineBuffer {
+    buffer: Vec<u8>,
+}
+
+impl LineBuffer {
+    fn feed(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut lines = Vec::new();
+        while let Some(position) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=position).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim();
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            }
+        }
+        lines
+    }
+}
+
+/// A request to the `/api/chat` endpoint.
+#[derive(Debug, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<serde_json::Value>,
+    pub options: ModelOptions,
+    pub stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_alive: Option<String>,
+}
+
+/// A message in a chat conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Message {
+    pub role: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
+    /// The name of the tool whose result the message contains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+}
+
+/// A tool the model may call.
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionDefinition,
+}
+
+/// The definition of a function the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool call made by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ToolCall {
+    pub function: FunctionCall,
+}
+
+/// The function called by a tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: serde_json::Value,
+}
+
+/// A response from the `/api/chat` endpoint, or a part of a streamed one.
+#[derive(Debug, Deserialize)]
+pub struct ChatResponse {
+    pub model: String,
+    pub message: Option<Message>,
+    #[serde(default)]
+    pub done: bool,
+    pub done_reason: Option<String>,
+    pub prompt_eval_count: Option<u32>,
+    pub eval_count: Option<u32>,
+}
+
+/// A response from the `/api/tags` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ListModelsResponse {
+    pub models: Vec<ModelInfo>,
+}
+
+/// A model installed on the Ollama server.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ModelInfo {
+    pub name: String,
+    pub model: String,
+    pub modified_at: String,
+    pub size: u64,
+    pub digest: String,
+    pub details: Option<ModelDetails>,
+}
+
+/// Details about an installed model.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ModelDetails {
+    pub format: Option<String>,
+    pub family: Option<String>,
+    pub parameter_size: Option<String>,
+    pub quantization_level: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_buffer_splits_across_chunks() {
+        let mut buffer = LineBuffer::default();
+        assert_eq!(buffer.feed(b"{\"a\":1}\n{\"b\""), ["{\"a\":1}"]);
+        assert_eq!(buffer.feed(b":2}\n\n"), ["{\"b\":2}"]);
+    }
+
+    #[test]
+    fn test_parse_response_reports_errors() {
+        let result = parse_response(r#"{"error": "model 'llama9' not found"}"#);
+        assert!(matches!(result, Err(err) if err.to_string().contains("llama9")));
+    }
+}
@@ -0,0 +1,373 @@
+//! This module provides the Ollama provider, which implements the `LanguageModel`
+//! and `Provider` traits for interacting with models served by a local Ollama
+//! server.
+
+pub mod client;
+pub mod settings;
+
+pub use client::ModelInfo;
+pub use settings::{ModelOptions, OllamaProviderSettings};
+
+use self::client::{ChatRequest, ChatResponse, FunctionDefinition, Message, OllamaClient, Tool};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
+    },
+    error::Result,
+    providers::{tool_call_count, user_text},
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The Ollama provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Ollama {
+    #[serde(skip)]
+    client: OllamaClient,
+    settings: OllamaProviderSettings,
+}
+
+impl Ollama {
+    /// Creates a new `Ollama` provider with the given settings.
+    pub fn new(settings: OllamaProviderSettings) -> Self {
+        let client = OllamaClient::new(settings.base_url.to_string())
+            .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Lists the models installed on the Ollama server.
+    pub async fn list_models(&self) -> Result<Vec<ModelInfo>> {
+        Ok(self.client.list_models().await?.models)
+    }
+
+    /// Builds a chat request from the provider-neutral call options.
+    fn chat_request(&self, options: LanguageModelCallOptions, stream: bool) -> Result<ChatRequest> {
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                kind: "function".to_string(),
+                function: FunctionDefinition {
+                    name: tool.name,
+                    description: tool.description,
+                    parameters: tool.parameters,
+                },
+            })
+            .collect::<Vec<_>>();
+        let format = match options.response_format {
+            Some(ResponseFormat::Json { schema, .. }) => {
+                Some(schema.unwrap_or_else(|| Value::String("json".to_string())))
+            }
+            Some(ResponseFormat::Text) | None => None,
+        };
+
+        let defaults = &self.settings.options;
+        let model_options = ModelOptions {
+            num_predict: options
+                .settings
+                .max_tokens
+                .map(|max_tokens| i32::try_from(max_tokens).unwrap_or(i32::MAX))
+                .or(defaults.num_predict),
+            temperature: options.settings.temperature.or(defaults.temperature),
+            top_p: options.settings.top_p.or(defaults.top_p),
+            top_k: options.settings.top_k.or(defaults.top_k),
+            presence_penalty: options
+                .settings
+                .presence_penalty
+                .or(defaults.presence_penalty),
+            frequency_penalty: options
+                .settings
+                .frequency_penalty
+                .or(defaults.frequency_penalty),
+            seed: options.settings.seed.or(defaults.seed),
+            stop: if options.settings.stop_sequences.is_empty() {
+                defaults.stop.clone()
+            } else {
+                options.settings.stop_sequences
+            },
+            ..defaults.clone()
+        };
+
+        Ok(ChatRequest {
+            model: self.model_name().to_string(),
+            messages: to_ollama_messages(options.system, options.messages)?,
+            tools: (!tools.is_empty()).then_some(tools),
+            format,
+            options: model_options,
+            stream,
+            keep_alive: self.settings.keep_alive.clone(),
+        })
+    }
+}
+
+impl Provider for Ollama {}
+
+#[async_trait]
+impl LanguageModel for Ollama {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let first_tool_call = tool_call_count(&options.messages);
+        let request = self.chat_request(options, false)?;
+
+        let response = self
+            .client
+            .generate_text(&request, retry_policy.as_ref())
+            .await?;
+        let usage = to_usage(&response);
+        let message = response.message.unwrap_or_else(|| Message {
+            role: "assistant".to_string(),
+            content: String::new(),
+            tool_calls: Vec::new(),
+            tool_name: None,
+        });
+        let tool_calls: Vec<_> = message
+            .tool_calls
+            .into_iter()
+            .enumerate()
+            .map(|(index, call)| to_tool_call(call, first_tool_call + index))
+            .collect();
+
+        Ok(LanguageModelResponse {
+            text: message.content,
+            model: Some(response.model),
+            finish_reason: to_finish_reason(
+                response.done_reason.as_deref(),
+                !tool_calls.is_empty(),
+            ),
+            tool_calls,
+            usage,
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let mut state = StreamState {
+            first_tool_call: tool_call_count(&options.messages),
+            ..Default::default()
+        };
+        let request = self.chat_request(options, true)?;
+
+        let responses = self
+            .client
+            .stream_text(&request, retry_policy.as_ref())
+            .await?;
+
+        Ok(responses
+            .map(move |response| match response {
+                Ok(response) => state.process(response),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts an Ollama done reason into a normalized finish reason. Ollama
+/// reports `stop` when the model calls tools, so the calls are taken into
+/// account.
+fn to_finish_reason(reason: Option<&str>, has_tool_calls: bool) -> FinishReason {
+    match reason {
+        None | Some("stop") if has_tool_calls => FinishReason::ToolCalls,
+        None | Some("stop") => FinishReason::Stop,
+        Some("length") => FinishReason::Length,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts the token counts of a final Ollama response into normalized
+/// token usage.
+fn to_usage(response: &ChatResponse) -> Option<Usage> {
+    if response.prompt_eval_count.is_none() && response.eval_count.is_none() {
+        return None;
+    }
+    let prompt_tokens = response.prompt_eval_count.unwrap_or(0);
+    let completion_tokens = response.eval_count.unwrap_or(0);
+    Some(Usage {
+        prompt_tokens,
+        completion_tokens,
+        total_tokens: prompt_tokens.saturating_add(completion_tokens),
+        ..Default::default()
+    })
+}
+
+/// Converts an Ollama tool call into a tool call. Ollama does not assign IDs
+/// to tool calls, so one is derived from the call's position in the
+/// conversation.
+fn to_tool_call(call: client::ToolCall, index: usize) -> ToolCall {
+    ToolCall {
+        id: format!("call_{index}"),
+        name: call.function.name,
+        arguments: call.function.arguments,
+    }
+}
+
+/// Converts streamed Ollama responses into provider-neutral stream chunks.
+#[derive(Debug, Default)]
+struct StreamState {
+    first_tool_call: usize,
+    tool_calls: usize,
+}
+
+impl StreamState {
+    fn process(&mut self, response: ChatResponse) -> Vec<LanguageModelStreamChunk> {
+        let mut chunks = Vec::new();
+
+        if let Some(message) = &response.message {
+            if !message.content.is_empty() {
+                chunks.push(LanguageModelStreamChunk::TextDelta(message.content.clone()));
+            }
+            for call in &message.tool_calls {
+                chunks.push(LanguageModelStreamChunk::ToolCall(to_tool_call(
+                    call.clone(),
+                    self.first_tool_call + self.tool_calls,
+                )));
+                self.tool_calls += 1;
+            }
+        }
+
+        if response.done {
+            chunks.push(LanguageModelStreamChunk::Finish {
+                finish_reason: to_finish_reason(
+                    response.done_reason.as_deref(),
+                    self.tool_calls > 0,
+                ),
+            });
+            if let Some(usage) = to_usage(&response) {
+                chunks.push(LanguageModelStreamChunk::Usage(usage));
+            }
+        }
+
+        chunks
+    }
+}
+
+/// Converts the provider-neutral conversation into Ollama messages. Tool
+/// results are named after the tool of the matching call.
+fn to_ollama_messages(
+    system: Option<String>,
+    messages: Vec<types::Message>,
+) -> Result<Vec<Message>> {
+    let mut tool_names: HashMap<String, String> = HashMap::new();
+    let message = |role: &str, content: String| Message {
+        role: role.to_string(),
+        content,
+        tool_calls: Vec::new(),
+        tool_name: None,
+    };
+
+    let system = system.map(|content| message("system", content));
+    let messages = messages.into_iter().map(|msg| {
+        Ok(match msg {
+            types::Message::System { content } => message("system", content),
+            types::Message::User { content } => message("user", user_text("Ollama", content)?),
+            types::Message::Assistant {
+                content,
+                tool_calls,
+            } => Message {
+                tool_calls: tool_calls
+                    .into_iter()
+                    .map(|call| {
+                        tool_names.insert(call.id, call.name.clone());
+                        client::ToolCall {
+                            function: client::FunctionCall {
+                                name: call.name,
+                                arguments: call.arguments,
+                            },
+                        }
+                    })
+                    .collect(),
+                ..message("assistant", content)
+            },
+            types::Message::Tool {
+                tool_call_id,
+                content,
+                ..
+            } => Message {
+                tool_name: tool_names.get(&tool_call_id).cloned(),
+                ..message("tool", content)
+            },
+        })
+    });
+
+    system.into_iter().map(Ok).chain(messages).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chat_request_merges_model_options() {
+        let settings = OllamaProviderSettings::builder()
+            .keep_alive("10m")
+            .options(
+                ModelOptions::builder()
+                    .num_ctx(8192)
+                    .temperature(0.2)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let options = LanguageModelCallOptions::builder()
+            .messages(vec![types::Message::user("hi")])
+            .temperature(0.9)
+            .max_tokens(64)
+            .build()
+            .unwrap();
+
+        let request = Ollama::new(settings).chat_request(options, false).unwrap();
+
+        assert_eq!(request.keep_alive.as_deref(), Some("10m"));
+        assert_eq!(
+            serde_json::to_value(&request.options).unwrap(),
+            json!({"num_ctx": 8192, "num_predict": 64, "temperature": 0.9_f32})
+        );
+    }
+
+    #[test]
+    fn test_to_ollama_messages_names_tool_results() {
+        let messages = to_ollama_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::user("weather in Paris?"),
+                types::Message::assistant_with_tool_calls(
+                    "",
+                    vec![ToolCall {
+                        id: "call_0".to_string(),
+                        name: "get_weather".to_string(),
+                        arguments: json!({"city": "Paris"}),
+                    }],
+                ),
+                types::Message::tool("call_0", "21"),
+            ],
+        )
+        .unwrap();
+
+        let roles: Vec<_> = messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["system", "user", "assistant", "tool"]);
+        assert_eq!(messages[2].tool_calls[0].function.name, "get_weather");
+        assert_eq!(messages[3].tool_name.as_deref(), Some("get_weather"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Defines the settings for the Ollama provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Ollama provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct OllamaProviderSettings {
+    /// The model to use for text generation.
+    #[builder(default = "\"llama3.2\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"ollama\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the Ollama server.
+    #[builder(default = "\"http://localhost:11434\".to_string()")]
+    pub base_url: String,
+
+    /// How long the model stays loaded after a request, e.g. `"10m"` or `"-1"`
+    /// to keep it loaded indefinitely. Uses the server's default when unset.
+    #[builder(default, setter(strip_option))]
+    pub keep_alive: Option<String>,
+
+    /// The model options sent with every request.
+    #[builder(default)]
+    pub options: ModelOptions,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl OllamaProviderSettings {
+    /// Creates a new builder for `OllamaProviderSettings`.
+    pub fn builder() -> OllamaProviderSettingsBuilder {
+        OllamaProviderSettingsBuilder::default()
+    }
+}
+
+/// Model options understood by the Ollama server.
+///
+/// Sampling parameters given in the call options take precedence over the
+/// ones set here.
+#[derive(Debug, Clone, Default, PartialEq, Builder, Serialize, Deserialize)]
+#[builder(
+    pattern = "owned",
+    setter(into = false, strip_option),
+    build_fn(error = "Error")
+)]
+pub struct ModelOptions {
+    /// The size of the context window in tokens.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_ctx: Option<u32>,
+
+    /// The maximum number of tokens to generate.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_predict: Option<i32>,
+
+    /// The number of tokens of the prompt kept when the context is full.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_keep: Option<i32>,
+
+    /// The number of layers offloaded to the GPU.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_gpu: Option<i32>,
+
+    /// The number of threads used for generation.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_thread: Option<u32>,
+
+    /// The sampling temperature.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// The nucleus sampling probability mass.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+
+    /// The number of most likely tokens to sample from.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+
+    /// The minimum probability of a token, relative to the most likely one.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_p: Option<f32>,
+
+    /// How strongly repetitions are penalized.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_penalty: Option<f32>,
+
+    /// How far back the model looks to penalize repetitions.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_last_n: Option<i32>,
+
+    /// The presence penalty.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+
+    /// The frequency penalty.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+
+    /// The random seed used for sampling.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+
+    /// Sequences that stop generation when produced.
+    #[builder(default, setter(into, strip_option = false))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+}
+
+impl ModelOptions {
+    /// Creates a new builder for `ModelOptions`.
+    pub fn builder() -> ModelOptionsBuilder {
+        ModelOptionsBuilder::default()
+    }
+}
@@ -2,51 +2,227 @@
 //! It handles the construction of requests, sending them to the API,
 //! and parsing the responses.
 
+use std::collections::BTreeMap;
+
+use futures::{StreamExt, stream::BoxStream};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::error::{Error, Result};
-
-const API_BASE_URL: &str = "https://api.openai.com/v1";
+use super::settings::EncodingFormat;
+use crate::core::retry::RetryPolicy;
+use crate::core::types::AudioFormat;
+use crate::error::{ApiErrorDetails, Error, Result};
+use crate::providers::sse;
 
-/// A client for the OpenAI API.
+/// A client for the OpenAI API and OpenAI-compatible endpoints.
 #[derive(Debug, Clone)]
 pub struct OpenAIClient {
     client: Client,
     api_key: String,
+    base_url: String,
+    headers: BTreeMap<String, String>,
+    query_params: BTreeMap<String, String>,
+    retry_policy: RetryPolicy,
 }
 
 impl OpenAIClient {
-    /// Creates a new `OpenAIClient` with the given API key.
-    pub fn new(api_key: String) -> Self {
+    /// Creates a new `OpenAIClient` for the API at the given base URL. The API
+    /// key is omitted from requests when it is empty.
+    pub fn new(api_key: String, base_url: String, headers: BTreeMap<String, String>) -> Self {
         Self {
             client: Client::new(),
             api_key,
+            base_url,
+            headers,
+            query_params: BTreeMap::new(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
-    /// Sends a request to the OpenAI API to generate text.
+    /// Adds query parameters sent with every request.
+    pub fn with_query_params(mut self, query_params: BTreeMap<String, String>) -> Self {
+        self.query_params.extend(query_params);
+        self
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the OpenAI API to generate text, retrying it
+    /// according to the given policy or, if unset, the client's.
     pub async fn generate_text(
         &self,
         request: &ChatCompletionRequest,
+        retry_policy: Option<&RetryPolicy>,
     ) -> Result<ChatCompletionResponse> {
-        let url = format!("{API_BASE_URL}/chat/completions");
+        let retry_policy = retry_policy.unwrap_or(&self.retry_policy);
         let response = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.api_key)
-            .json(request)
-            .send()
+            .execute(self.post("/chat/completions").json(request), retry_policy)
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a streaming request to the OpenAI API to generate text.
+    ///
+    /// The returned stream yields the parsed server-sent events until the
+    /// API signals the end of the stream. Only the initial request is
+    /// retried, according to the given policy or, if unset, the client's.
+    pub async fn stream_text(
+        &self,
+        request: &ChatCompletionRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<ChatCompletionChunk>>> {
+        let retry_policy = retry_policy.unwrap_or(&self.retry_policy);
+        let response = self
+            .execute(self.post("/chat/completions").json(request), retry_policy)
+            .await?;
+
+        let chunks = sse::data_stream(response)
+            .filter(|data| futures::future::ready(!matches!(data, Ok(data) if data == "[DONE]")))
+            .map(|data| data.and_then(|data| parse_chunk(&data)));
+
+        Ok(chunks.boxed())
+    }
+
+    /// Sends a request to the OpenAI API to embed a batch of values.
+    pub async fn embed(&self, request: &EmbeddingRequest) -> Result<EmbeddingResponse> {
+        let response = self.send("/embeddings", request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request to the OpenAI API to generate images.
+    pub async fn generate_image(
+        &self,
+        request: &ImageGenerationRequest,
+    ) -> Result<ImageGenerationResponse> {
+        let response = self.send("/images/generations", request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request to the OpenAI API to synthesize speech.
+    ///
+    /// The returned response streams the audio bytes as they arrive.
+    pub async fn generate_speech(&self, request: &SpeechRequest) -> Result<SpeechResponse> {
+        let response = self.send("/audio/speech", request).await?;
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let audio = response
+            .bytes_stream()
+            .map(|bytes| Ok(bytes?.to_vec()))
+            .boxed();
+
+        Ok(SpeechResponse {
+            content_type,
+            audio,
+        })
+    }
+
+    /// Sends a request to the OpenAI API to transcribe an audio file.
+    ///
+    /// A multipart body cannot be cloned, so the form is built again for
+    /// every attempt.
+    pub async fn transcribe(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> Result<TranscriptionResponse> {
+        let response = self
+            .retry_policy
+            .retry(|| async {
+                let form = transcription_form(request)?;
+                Self::send_once(self.post("/audio/transcriptions").multipart(form)).await
+            })
             .await?;
+        Ok(response.json().await?)
+    }
+
+    async fn send(&self, path: &str, request: &impl Serialize) -> Result<reqwest::Response> {
+        self.execute(self.post(path).json(request), &self.retry_policy)
+            .await
+    }
+
+    /// Creates a POST request to the given path with the authentication,
+    /// headers and query parameters of this client.
+    fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .client
+            .post(format!("{}{path}", self.base_url))
+            .query(&self.query_params);
+        if !self.api_key.is_empty() {
+            builder = builder.bearer_auth(&self.api_key);
+        }
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder
+    }
+
+    /// Sends a request with a JSON body, retrying it according to the given
+    /// policy.
+    async fn execute(
+        &self,
+        builder: reqwest::RequestBuilder,
+        retry_policy: &RetryPolicy,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .retry(|| {
+                let builder = builder
+                    .try_clone()
+                    .expect("requests with a JSON body can be cloned");
+                Self::send_once(builder)
+            })
+            .await
+    }
+
+    async fn send_once(builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let response = builder.send().await?;
 
         if !response.status().is_success() {
-            return Err(Error::ApiError(response.text().await?));
+            return Err(Error::from_response(response).await);
         }
 
-        Ok(response.json().await?)
+        Ok(response)
     }
 }
 
+/// Builds the multipart form of a transcription request.
+fn transcription_form(request: &TranscriptionRequest) -> Result<reqwest::multipart::Form> {
+    let file = reqwest::multipart::Part::bytes(request.file.clone())
+        .file_name(request.file_name.clone())
+        .mime_str(&request.media_type)?;
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", file)
+        .text("model", request.model.clone())
+        .text("response_format", request.response_format.clone());
+    for granularity in &request.timestamp_granularities {
+        form = form.text("timestamp_granularities[]", granularity.clone());
+    }
+    if let Some(language) = &request.language {
+        form = form.text("language", language.clone());
+    }
+    if let Some(prompt) = &request.prompt {
+        form = form.text("prompt", prompt.clone());
+    }
+    Ok(form)
+}
+
+/// Parses the data of a server-sent event into a `ChatCompletionChunk`.
+fn parse_chunk(data: &str) -> Result<ChatCompletionChunk> {
+    serde_json::from_str(data).map_err(|err| {
+        let value: serde_json::Value = serde_json::from_str(data).unwrap_or_default();
+        match value.get("error") {
+            Some(_) => Error::from_api_error(ApiErrorDetails::parse(None, data), None),
+            None => Error::from(err),
+        }
+    })
+}
+
 // TODO: improve the request and response types to fully match the OpenAI API
 
 /// A request to the OpenAI API to generate a chat completion.
@@ -54,14 +230,160 @@ impl OpenAIClient {
 pub struct ChatCompletionRequest {
     pub model: String,
     pub messages: Vec<Message>,
-    pub max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_completion_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ChatTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ChatResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
+}
+
+/// The format the model must respond in.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+/// A JSON Schema the model's response must match.
+#[derive(Debug, Serialize)]
+pub struct JsonSchemaFormat {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub schema: serde_json::Value,
+    pub strict: bool,
+}
+
+/// Options for a streaming chat completion request.
+#[derive(Debug, Serialize)]
+pub struct StreamOptions {
+    pub include_usage: bool,
 }
 
 /// A message in a chat completion request.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Message {
     pub role: String,
-    pub content: String,
+    pub content: Option<MessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ChatToolCall>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+/// The content of a message, either plain text or a list of parts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+impl MessageContent {
+    /// Returns the text of the content, joining the text parts.
+    pub fn into_text(self) -> String {
+        match self {
+            MessageContent::Text(text) => text,
+            MessageContent::Parts(parts) => parts
+                .into_iter()
+                .filter_map(|part| match part {
+                    ContentPart::Text { text } => Some(text),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<String> for MessageContent {
+    fn from(text: String) -> Self {
+        MessageContent::Text(text)
+    }
+}
+
+/// A part of the content of a message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+    InputAudio { input_audio: InputAudio },
+    File { file: FileData },
+}
+
+/// An image, referenced by URL or embedded as a data URL.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ImageUrl {
+    pub url: String,
+}
+
+/// Base64 encoded audio in the given format, e.g. `wav` or `mp3`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InputAudio {
+    pub data: String,
+    pub format: String,
+}
+
+/// A file embedded as a data URL.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FileData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    pub file_data: String,
+}
+
+/// A tool that the model may call.
+#[derive(Debug, Serialize)]
+pub struct ChatTool {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionDefinition,
+}
+
+/// The definition of a function that the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool call generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChatToolCall {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionCall,
+}
+
+/// The function that the model called.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments to call the function with, as a JSON encoded string.
+    pub arguments: String,
 }
 
 /// A response from the OpenAI API for a chat completion request.
@@ -72,6 +394,7 @@ pub struct ChatCompletionResponse {
     pub created: u64,
     pub model: String,
     pub choices: Vec<ChatChoice>,
+    pub usage: Option<CompletionUsage>,
 }
 
 /// A choice in a chat completion response.
@@ -79,5 +402,239 @@ pub struct ChatCompletionResponse {
 pub struct ChatChoice {
     pub index: u32,
     pub message: Message,
-    pub finish_reason: String,
+    pub finish_reason: Option<String>,
+    #[serde(default)]
+    pub content_filter_results: BTreeMap<String, ContentFilterResult>,
+}
+
+/// The result of a content filter category, as reported by Azure OpenAI.
+#[derive(Debug, Deserialize)]
+pub struct ContentFilterResult {
+    #[serde(default)]
+    pub filtered: bool,
+    pub severity: Option<String>,
+}
+
+/// Token usage reported by the OpenAI API.
+#[derive(Debug, Deserialize)]
+pub struct CompletionUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+/// A breakdown of the tokens in the prompt.
+#[derive(Debug, Deserialize)]
+pub struct PromptTokensDetails {
+    pub cached_tokens: Option<u32>,
+}
+
+/// A breakdown of the tokens in the completion.
+#[derive(Debug, Deserialize)]
+pub struct CompletionTokensDetails {
+    pub reasoning_tokens: Option<u32>,
+}
+
+/// A chunk of a streamed chat completion response.
+#[derive(Debug, Deserialize)]
+pub struct ChatCompletionChunk {
+    pub id: String,
+    pub model: String,
+    pub choices: Vec<ChatChunkChoice>,
+    pub usage: Option<CompletionUsage>,
+}
+
+/// A choice in a streamed chat completion chunk.
+#[derive(Debug, Deserialize)]
+pub struct ChatChunkChoice {
+    pub index: u32,
+    #[serde(default)]
+    pub delta: ChatDelta,
+    pub finish_reason: Option<String>,
+    #[serde(default)]
+    pub content_filter_results: BTreeMap<String, ContentFilterResult>,
+}
+
+/// The incremental message content of a streamed choice.
+#[derive(Debug, Default, Deserialize)]
+pub struct ChatDelta {
+    pub role: Option<String>,
+    pub content: Option<String>,
+    pub tool_calls: Option<Vec<ChatToolCallDelta>>,
+}
+
+/// A fragment of a tool call in a streamed choice.
+///
+/// The id and function name are only sent with the first fragment of each
+/// call, while the arguments are spread across fragments sharing an index.
+#[derive(Debug, Deserialize)]
+pub struct ChatToolCallDelta {
+    pub index: u32,
+    pub id: Option<String>,
+    pub function: Option<FunctionCallDelta>,
+}
+
+/// A fragment of a function call in a streamed choice.
+#[derive(Debug, Deserialize)]
+pub struct FunctionCallDelta {
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+/// A request to the OpenAI API to embed a batch of values.
+#[derive(Debug, Serialize)]
+pub struct EmbeddingRequest {
+    pub model: String,
+    pub input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+    pub encoding_format: EncodingFormat,
+}
+
+/// A response from the OpenAI API for an embedding request.
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingResponse {
+    pub model: String,
+    pub data: Vec<EmbeddingData>,
+    pub usage: Option<EmbeddingUsage>,
+}
+
+/// The embedding of a single value.
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingData {
+    pub index: usize,
+    pub embedding: EmbeddingVector,
+}
+
+/// An embedding, in the encoding format of the request.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EmbeddingVector {
+    Float(Vec<f32>),
+    Base64(String),
+}
+
+/// Token usage reported by the OpenAI API for an embedding request.
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingUsage {
+    pub prompt_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// A request to the OpenAI API to generate images.
+#[derive(Debug, Serialize)]
+pub struct ImageGenerationRequest {
+    pub model: String,
+    pub prompt: String,
+    pub n: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// Either `url` or `b64_json`. Models that always return base64 encoded
+    /// images reject it, so it is only sent when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<String>,
+}
+
+/// A response from the OpenAI API for an image generation request.
+#[derive(Debug, Deserialize)]
+pub struct ImageGenerationResponse {
+    pub created: u64,
+    pub data: Vec<ImageObject>,
+}
+
+/// A generated image, either as a URL or base64 encoded.
+#[derive(Debug, Deserialize)]
+pub struct ImageObject {
+    pub url: Option<String>,
+    pub b64_json: Option<String>,
+    pub revised_prompt: Option<String>,
+}
+
+/// A request to the OpenAI API to synthesize speech.
+#[derive(Debug, Serialize)]
+pub struct SpeechRequest {
+    pub model: String,
+    pub input: String,
+    pub voice: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<AudioFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+/// A response from the OpenAI API for a speech request.
+pub struct SpeechResponse {
+    /// The value of the `Content-Type` header.
+    pub content_type: Option<String>,
+
+    /// The stream of audio bytes.
+    pub audio: BoxStream<'static, Result<Vec<u8>>>,
+}
+
+impl std::fmt::Debug for SpeechResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpeechResponse")
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A request to the OpenAI API to transcribe an audio file, sent as a
+/// multipart form.
+#[derive(Debug)]
+pub struct TranscriptionRequest {
+    pub file: Vec<u8>,
+    pub file_name: String,
+    pub media_type: String,
+    pub model: String,
+    pub response_format: String,
+    pub timestamp_granularities: Vec<String>,
+    pub language: Option<String>,
+    pub prompt: Option<String>,
+}
+
+/// A response from the OpenAI API for a transcription request. Only the
+/// `verbose_json` response format reports the language, duration and
+/// segments.
+#[derive(Debug, Deserialize)]
+pub struct TranscriptionResponse {
+    pub text: String,
+    pub language: Option<String>,
+    pub duration: Option<f32>,
+    #[serde(default)]
+    pub segments: Vec<TranscriptionSegment>,
+}
+
+/// A timestamped segment of a transcription.
+#[derive(Debug, Deserialize)]
+pub struct TranscriptionSegment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chunk() {
+        let chunk = parse_chunk(
+            r#"{"id":"1","object":"chat.completion.chunk","created":0,"model":"gpt-4o",
+                "choices":[{"index":0,"delta":{"content":"Hi"},"finish_reason":null}]}"#,
+        )
+        .unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+
+        let error = parse_chunk(r#"{"error":{"message":"boom"}}"#).unwrap_err();
+        assert!(matches!(error, Error::ServerError(_)));
+    }
 }
@@ -0,0 +1,105 @@
+//! This module provides the OpenAI embedding model, which implements the
+//! `EmbeddingModel` trait for the OpenAI `/embeddings` endpoint.
+
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::Serialize;
+
+use super::client::{EmbeddingData, EmbeddingRequest, EmbeddingVector, OpenAIClient};
+use super::settings::OpenAIEmbeddingSettings;
+use crate::{
+    core::{
+        embedding_model::EmbeddingModel,
+        types::{Embedding, EmbeddingModelCallOptions, EmbeddingModelResponse, EmbeddingUsage},
+    },
+    error::{Error, Result},
+};
+
+/// An OpenAI embedding model.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenAIEmbeddingModel {
+    #[serde(skip)]
+    client: OpenAIClient,
+    settings: OpenAIEmbeddingSettings,
+}
+
+impl OpenAIEmbeddingModel {
+    /// Creates a new `OpenAIEmbeddingModel` that sends its requests with the
+    /// given client.
+    pub(crate) fn new(client: OpenAIClient, settings: OpenAIEmbeddingSettings) -> Self {
+        Self { client, settings }
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for OpenAIEmbeddingModel {
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn max_embeddings_per_call(&self) -> Option<usize> {
+        Some(self.settings.max_embeddings_per_call)
+    }
+
+    async fn embed(&self, options: EmbeddingModelCallOptions) -> Result<EmbeddingModelResponse> {
+        let request = EmbeddingRequest {
+            model: self.settings.model_name.to_string(),
+            input: options.values,
+            dimensions: self.settings.dimensions,
+            encoding_format: self.settings.encoding_format,
+        };
+
+        let response = self.client.embed(&request).await?;
+
+        let mut data = response.data;
+        data.sort_by_key(|data| data.index);
+        let embeddings = data
+            .into_iter()
+            .map(|EmbeddingData { embedding, .. }| to_embedding(embedding))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(EmbeddingModelResponse {
+            embeddings,
+            usage: response.usage.map(|usage| EmbeddingUsage {
+                tokens: usage.prompt_tokens,
+            }),
+        })
+    }
+}
+
+/// Converts an embedding in either encoding format into floats.
+fn to_embedding(vector: EmbeddingVector) -> Result<Embedding> {
+    match vector {
+        EmbeddingVector::Float(embedding) => Ok(embedding),
+        EmbeddingVector::Base64(encoded) => {
+            let bytes = STANDARD
+                .decode(encoded)
+                .map_err(|err| Error::ParseError(format!("invalid base64 embedding: {err}")))?;
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_embedding_decodes_base64() {
+        let bytes: Vec<u8> = [0.5f32, -1.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+
+        let embedding = to_embedding(EmbeddingVector::Base64(STANDARD.encode(bytes))).unwrap();
+
+        assert_eq!(embedding, vec![0.5, -1.0]);
+    }
+}
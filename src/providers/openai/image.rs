@@ -0,0 +1,106 @@
+//! This module provides the OpenAI image model, which implements the
+//! `ImageModel` trait for the OpenAI `/images/generations` endpoint.
+
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::Serialize;
+
+use super::client::{ImageGenerationRequest, ImageObject, OpenAIClient};
+use super::settings::OpenAIImageSettings;
+use crate::{
+    core::{
+        image_model::ImageModel,
+        types::{
+            GeneratedImage, ImageData, ImageModelCallOptions, ImageModelResponse, ImageOutput,
+        },
+    },
+    error::{Error, Result},
+};
+
+/// An OpenAI image model.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenAIImageModel {
+    #[serde(skip)]
+    client: OpenAIClient,
+    settings: OpenAIImageSettings,
+}
+
+impl OpenAIImageModel {
+    /// Creates a new `OpenAIImageModel` that sends its requests with the
+    /// given client.
+    pub(crate) fn new(client: OpenAIClient, settings: OpenAIImageSettings) -> Self {
+        Self { client, settings }
+    }
+}
+
+#[async_trait]
+impl ImageModel for OpenAIImageModel {
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn max_images_per_call(&self) -> Option<u32> {
+        Some(self.settings.max_images_per_call.unwrap_or(
+            if self.settings.model_name == "dall-e-3" {
+                1
+            } else {
+                10
+            },
+        ))
+    }
+
+    async fn generate(&self, options: ImageModelCallOptions) -> Result<ImageModelResponse> {
+        let request = ImageGenerationRequest {
+            model: self.settings.model_name.to_string(),
+            prompt: options.prompt,
+            n: options.n,
+            size: options.size,
+            quality: options.quality,
+            style: options.style,
+            response_format: options.output.map(|output| {
+                match output {
+                    ImageOutput::Bytes => "b64_json",
+                    ImageOutput::Url => "url",
+                }
+                .to_string()
+            }),
+        };
+
+        let response = self.client.generate_image(&request).await?;
+
+        let images = response
+            .data
+            .into_iter()
+            .map(to_generated_image)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ImageModelResponse { images })
+    }
+}
+
+/// Converts an OpenAI image object into a generated image, decoding base64
+/// encoded images.
+fn to_generated_image(image: ImageObject) -> Result<GeneratedImage> {
+    let data = match (image.b64_json, image.url) {
+        (Some(encoded), _) => ImageData::Bytes(
+            STANDARD
+                .decode(encoded)
+                .map_err(|err| Error::ParseError(format!("invalid base64 image: {err}")))?,
+        ),
+        (None, Some(url)) => ImageData::Url(url),
+        (None, None) => {
+            return Err(Error::ParseError(
+                "image contained neither a URL nor data".to_string(),
+            ));
+        }
+    };
+
+    Ok(GeneratedImage {
+        data,
+        revised_prompt: image.revised_prompt,
+    })
+}
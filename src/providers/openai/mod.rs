@@ -2,21 +2,44 @@
 //! and `Provider` traits for interacting with the OpenAI API.
 
 pub mod client;
+pub mod embedding;
+pub mod image;
 pub mod settings;
+pub mod speech;
+pub mod transcription;
 
-pub use settings::OpenAIProviderSettings;
+pub use embedding::OpenAIEmbeddingModel;
+pub use image::OpenAIImageModel;
+pub use settings::{
+    Compatibility, EncodingFormat, OpenAIEmbeddingSettings, OpenAIImageSettings,
+    OpenAIProviderSettings, OpenAISpeechSettings, OpenAITranscriptionSettings,
+};
+pub use speech::OpenAISpeechModel;
+pub use transcription::OpenAITranscriptionModel;
 
-use self::client::{ChatCompletionRequest, Message, OpenAIClient};
+use self::client::{
+    ChatCompletionChunk, ChatCompletionRequest, ChatResponseFormat, ChatTool, ChatToolCall,
+    CompletionUsage, ContentFilterResult, ContentPart, FileData, FunctionCall, FunctionDefinition,
+    ImageUrl, InputAudio, JsonSchemaFormat, Message, MessageContent, OpenAIClient, StreamOptions,
+};
 use crate::{
     core::{
         language_model::LanguageModel,
+        media_type,
         provider::Provider,
-        types::{LanguageModelCallOptions, LanguageModelResponse},
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
     },
-    error::Result,
+    error::{Error, Result},
+    providers::{parse_tool_arguments, strict_json_schema},
 };
 use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use futures::{StreamExt, stream};
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// The OpenAI provider.
 #[derive(Debug, Clone, Serialize)]
@@ -29,9 +52,92 @@ pub struct OpenAI {
 impl OpenAI {
     /// Creates a new `OpenAI` provider with the given settings.
     pub fn new(settings: OpenAIProviderSettings) -> Self {
-        let client = OpenAIClient::new(settings.api_key.to_string());
+        let client = OpenAIClient::new(
+            settings.api_key.to_string(),
+            settings.base_url.to_string(),
+            settings.headers.clone(),
+        )
+        .with_query_params(settings.query_params.clone())
+        .with_retry_policy(settings.retry_policy.clone());
         Self { client, settings }
     }
+
+    /// Creates an embedding model that shares the API key, base URL, headers
+    /// and query parameters of this provider.
+    pub fn embedding_model(&self, settings: OpenAIEmbeddingSettings) -> OpenAIEmbeddingModel {
+        OpenAIEmbeddingModel::new(self.client.clone(), settings)
+    }
+
+    /// Creates an image model that shares the API key, base URL, headers and
+    /// query parameters of this provider.
+    pub fn image_model(&self, settings: OpenAIImageSettings) -> OpenAIImageModel {
+        OpenAIImageModel::new(self.client.clone(), settings)
+    }
+
+    /// Creates a speech model that shares the API key, base URL, headers and
+    /// query parameters of this provider.
+    pub fn speech_model(&self, settings: OpenAISpeechSettings) -> OpenAISpeechModel {
+        OpenAISpeechModel::new(self.client.clone(), settings)
+    }
+
+    /// Creates a transcription model that shares the API key, base URL,
+    /// headers and query parameters of this provider.
+    pub fn transcription_model(
+        &self,
+        settings: OpenAITranscriptionSettings,
+    ) -> OpenAITranscriptionModel {
+        OpenAITranscriptionModel::new(self.client.clone(), settings)
+    }
+
+    /// Builds a chat completion request from the provider-neutral call options.
+    fn chat_request(
+        &self,
+        options: LanguageModelCallOptions,
+        stream: bool,
+    ) -> ChatCompletionRequest {
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| ChatTool {
+                kind: "function".to_string(),
+                function: FunctionDefinition {
+                    name: tool.name,
+                    description: tool.description,
+                    parameters: tool.parameters,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        if options.settings.top_k.is_some() {
+            log::warn!("OpenAI does not support `top_k`, ignoring it");
+        }
+
+        let compatibility = &self.settings.compatibility;
+        let max_tokens = options
+            .settings
+            .max_tokens
+            .unwrap_or(self.settings.max_tokens);
+
+        ChatCompletionRequest {
+            model: self.model_name().to_string(),
+            messages: to_openai_messages(options.system, options.messages),
+            max_tokens: (!compatibility.max_completion_tokens).then_some(max_tokens),
+            max_completion_tokens: compatibility.max_completion_tokens.then_some(max_tokens),
+            temperature: options.settings.temperature,
+            top_p: options.settings.top_p,
+            presence_penalty: options.settings.presence_penalty,
+            frequency_penalty: options.settings.frequency_penalty,
+            stop: (!options.settings.stop_sequences.is_empty())
+                .then_some(options.settings.stop_sequences),
+            seed: options.settings.seed,
+            tools: (!tools.is_empty()).then_some(tools),
+            response_format: options.response_format.map(to_openai_response_format),
+            stream: stream.then_some(true),
+            stream_options: (stream && compatibility.stream_usage).then_some(StreamOptions {
+                include_usage: true,
+            }),
+        }
+    }
 }
 
 impl Provider for OpenAI {}
@@ -46,22 +152,607 @@ impl LanguageModel for OpenAI {
         &self.settings.model_name
     }
 
+    fn supports_structured_output(&self) -> bool {
+        self.settings.compatibility.structured_outputs
+    }
+
     async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
-        let request = ChatCompletionRequest {
-            model: self.model_name().to_string(),
-            // TODO: support multiple messages
-            messages: vec![Message {
-                role: "user".to_string(),
-                content: options.prompt,
-            }],
-            max_tokens: self.settings.max_tokens,
-        };
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, false);
+
+        let response = self
+            .client
+            .generate_text(&request, retry_policy.as_ref())
+            .await?;
+        let choice = response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("response contained no choices".to_string()))?;
+        let message = choice.message;
 
-        let response = self.client.generate_text(&request).await?;
+        let tool_calls = message
+            .tool_calls
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| ToolCall {
+                id: call.id,
+                name: call.function.name,
+                arguments: parse_tool_arguments(&call.function.arguments),
+            })
+            .collect();
 
         Ok(LanguageModelResponse {
-            text: response.choices[0].message.content.to_string(),
+            text: message
+                .content
+                .map(MessageContent::into_text)
+                .unwrap_or_default(),
             model: Some(response.model),
+            tool_calls,
+            finish_reason: if is_filtered(&choice.content_filter_results) {
+                FinishReason::ContentFilter
+            } else {
+                to_finish_reason(choice.finish_reason.as_deref())
+            },
+            usage: response.usage.map(to_usage),
         })
     }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        let request = self.chat_request(options, true);
+
+        let chunks = self
+            .client
+            .stream_text(&request, retry_policy.as_ref())
+            .await?;
+
+        let mut state = StreamState::default();
+        Ok(chunks
+            .map(move |chunk| match chunk {
+                Ok(chunk) => state.process(chunk),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts the provider-neutral response format into an OpenAI response format.
+fn to_openai_response_format(format: ResponseFormat) -> ChatResponseFormat {
+    match format {
+        ResponseFormat::Text => ChatResponseFormat::Text,
+        ResponseFormat::Json { schema: None, .. } => ChatResponseFormat::JsonObject,
+        ResponseFormat::Json {
+            schema: Some(schema),
+            name,
+            description,
+        } => ChatResponseFormat::JsonSchema {
+            json_schema: JsonSchemaFormat {
+                name: sanitize_schema_name(name.as_deref().unwrap_or("response")),
+                description,
+                schema: strict_json_schema(schema),
+                strict: true,
+            },
+        },
+    }
+}
+
+/// Replaces characters that OpenAI does not allow in schema names.
+fn sanitize_schema_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Converts an OpenAI finish reason into a normalized finish reason.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("stop") => FinishReason::Stop,
+        Some("length") => FinishReason::Length,
+        Some("tool_calls" | "function_call") => FinishReason::ToolCalls,
+        Some("content_filter") => FinishReason::ContentFilter,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Returns whether a content filter withheld any part of a choice. Only Azure
+/// OpenAI reports these results.
+fn is_filtered(results: &BTreeMap<String, ContentFilterResult>) -> bool {
+    results.values().any(|result| result.filtered)
+}
+
+/// Converts OpenAI token usage into normalized token usage.
+fn to_usage(usage: CompletionUsage) -> Usage {
+    Usage {
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+        cached_tokens: usage
+            .prompt_tokens_details
+            .and_then(|details| details.cached_tokens),
+        reasoning_tokens: usage
+            .completion_tokens_details
+            .and_then(|details| details.reasoning_tokens),
+    }
+}
+
+/// A tool call whose fragments are still being streamed.
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// Converts streamed OpenAI chunks into provider-neutral stream chunks,
+/// assembling tool call fragments into complete calls.
+#[derive(Debug, Default)]
+struct StreamState {
+    tool_calls: BTreeMap<u32, PendingToolCall>,
+    filtered: bool,
+}
+
+impl StreamState {
+    fn process(&mut self, chunk: ChatCompletionChunk) -> Vec<LanguageModelStreamChunk> {
+        let mut chunks = Vec::new();
+        for choice in chunk.choices {
+            self.filtered |= is_filtered(&choice.content_filter_results);
+            if let Some(content) = choice.delta.content.filter(|c| !c.is_empty()) {
+                chunks.push(LanguageModelStreamChunk::TextDelta(content));
+            }
+            for delta in choice.delta.tool_calls.unwrap_or_default() {
+                let pending = self.tool_calls.entry(delta.index).or_default();
+                if let Some(id) = delta.id {
+                    pending.id = id;
+                }
+                if let Some(function) = delta.function {
+                    pending.name.push_str(&function.name.unwrap_or_default());
+                    pending
+                        .arguments
+                        .push_str(&function.arguments.unwrap_or_default());
+                }
+            }
+            if let Some(finish_reason) = choice.finish_reason {
+                for (_, call) in std::mem::take(&mut self.tool_calls) {
+                    chunks.push(LanguageModelStreamChunk::ToolCall(ToolCall {
+                        id: call.id,
+                        name: call.name,
+                        arguments: parse_tool_arguments(&call.arguments),
+                    }));
+                }
+                chunks.push(LanguageModelStreamChunk::Finish {
+                    finish_reason: if self.filtered {
+                        FinishReason::ContentFilter
+                    } else {
+                        to_finish_reason(Some(&finish_reason))
+                    },
+                });
+            }
+        }
+        if let Some(usage) = chunk.usage {
+            chunks.push(LanguageModelStreamChunk::Usage(to_usage(usage)));
+        }
+        chunks
+    }
+}
+
+/// Converts the provider-neutral conversation into OpenAI chat messages,
+/// placing the system prompt first.
+fn to_openai_messages(system: Option<String>, messages: Vec<types::Message>) -> Vec<Message> {
+    let system = system.map(types::Message::system);
+    system
+        .into_iter()
+        .chain(messages)
+        .map(|message| {
+            let role = match message.role() {
+                types::Role::System => "system",
+                types::Role::User => "user",
+                types::Role::Assistant => "assistant",
+                types::Role::Tool => "tool",
+            };
+            let (content, tool_calls, tool_call_id) = match message {
+                types::Message::System { content } => (Some(content.into()), None, None),
+                types::Message::User { content } => (Some(to_openai_content(content)), None, None),
+                types::Message::Assistant {
+                    content,
+                    tool_calls,
+                } if !tool_calls.is_empty() => {
+                    let tool_calls = tool_calls
+                        .into_iter()
+                        .map(|call| ChatToolCall {
+                            id: call.id,
+                            kind: "function".to_string(),
+                            function: FunctionCall {
+                                name: call.name,
+                                arguments: call.arguments.to_string(),
+                            },
+                        })
+                        .collect();
+                    (
+                        (!content.is_empty()).then(|| content.into()),
+                        Some(tool_calls),
+                        None,
+                    )
+                }
+                types::Message::Assistant { content, .. } => (Some(content.into()), None, None),
+                types::Message::Tool {
+                    tool_call_id,
+                    content,
+                    ..
+                } => (Some(content.into()), None, Some(tool_call_id)),
+            };
+            Message {
+                role: role.to_string(),
+                content,
+                tool_calls,
+                tool_call_id,
+            }
+        })
+        .collect()
+}
+
+/// Converts the parts of a user message into OpenAI message content. A
+/// single text part is sent as plain text, which every OpenAI-compatible
+/// endpoint accepts.
+fn to_openai_content(content: Vec<types::ContentPart>) -> MessageContent {
+    if let [types::ContentPart::Text { text }] = content.as_slice() {
+        return MessageContent::Text(text.to_string());
+    }
+
+    let parts = content
+        .into_iter()
+        .map(|part| match part {
+            types::ContentPart::Text { text } => ContentPart::Text { text },
+            types::ContentPart::Image { source } => ContentPart::ImageUrl {
+                image_url: ImageUrl {
+                    url: match source {
+                        types::ImageSource::Url { url } => url,
+                        types::ImageSource::Bytes { data, media_type } => {
+                            data_url(&media_type, &data)
+                        }
+                    },
+                },
+            },
+            types::ContentPart::Audio { data, media_type } => ContentPart::InputAudio {
+                input_audio: InputAudio {
+                    data: STANDARD.encode(data),
+                    format: media_type::extension(&media_type)
+                        .unwrap_or("wav")
+                        .to_string(),
+                },
+            },
+            types::ContentPart::File {
+                data,
+                media_type,
+                filename,
+            } => ContentPart::File {
+                file: FileData {
+                    filename,
+                    file_data: data_url(&media_type, &data),
+                },
+            },
+        })
+        .collect();
+    MessageContent::Parts(parts)
+}
+
+/// Encodes data as a base64 data URL.
+fn data_url(media_type: &str, data: &[u8]) -> String {
+    format!("data:{media_type};base64,{}", STANDARD.encode(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_openai_messages_places_system_first() {
+        let messages = to_openai_messages(
+            Some("be brief".to_string()),
+            vec![
+                types::Message::user("hi"),
+                types::Message::assistant("hello"),
+                types::Message::tool("call_1", "42"),
+            ],
+        );
+
+        let roles: Vec<_> = messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["system", "user", "assistant", "tool"]);
+        assert_eq!(
+            messages[0].content,
+            Some(MessageContent::Text("be brief".to_string()))
+        );
+        assert_eq!(messages[3].tool_call_id.as_deref(), Some("call_1"));
+    }
+
+    #[test]
+    fn test_to_openai_messages_encodes_assistant_tool_calls() {
+        let messages = to_openai_messages(
+            None,
+            vec![types::Message::assistant_with_tool_calls(
+                "",
+                vec![ToolCall {
+                    id: "call_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: serde_json::json!({"city": "Paris"}),
+                }],
+            )],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&messages[0]).unwrap(),
+            serde_json::json!({
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_openai_messages_encodes_content_parts() {
+        let messages = to_openai_messages(
+            None,
+            vec![types::Message::user_with_parts(vec![
+                types::ContentPart::text("What is shown?"),
+                types::ContentPart::image_url("https://example.com/cat.png"),
+                types::ContentPart::image(b"abc".to_vec(), "image/png"),
+                types::ContentPart::audio(b"abc".to_vec(), "audio/mpeg"),
+                types::ContentPart::file(b"abc".to_vec(), "application/pdf"),
+            ])],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&messages[0].content).unwrap(),
+            serde_json::json!([
+                {"type": "text", "text": "What is shown?"},
+                {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}},
+                {"type": "image_url", "image_url": {"url": "data:image/png;base64,YWJj"}},
+                {"type": "input_audio", "input_audio": {"data": "YWJj", "format": "mp3"}},
+                {"type": "file", "file": {"file_data": "data:application/pdf;base64,YWJj"}}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_chat_request_maps_sampling_settings() {
+        let openai = OpenAI::new(
+            OpenAIProviderSettings::builder()
+                .api_key("test")
+                .max_tokens(100u32)
+                .build()
+                .unwrap(),
+        );
+        let options = LanguageModelCallOptions::builder()
+            .messages(vec![types::Message::user("hi")])
+            .temperature(0.5)
+            .stop_sequences(vec!["END".to_string()])
+            .max_tokens(20)
+            .build()
+            .unwrap();
+
+        let request = serde_json::to_value(openai.chat_request(options, false)).unwrap();
+
+        assert_eq!(request["max_tokens"], 20);
+        assert_eq!(request["temperature"], 0.5);
+        assert_eq!(request["stop"], serde_json::json!(["END"]));
+        assert!(request.get("top_p").is_none());
+    }
+
+    #[test]
+    fn test_chat_request_applies_compatibility() {
+        let openai = OpenAI::new(
+            OpenAIProviderSettings::builder()
+                .api_key("")
+                .compatibility(Compatibility {
+                    structured_outputs: false,
+                    stream_usage: false,
+                    max_completion_tokens: true,
+                })
+                .build()
+                .unwrap(),
+        );
+        let options = LanguageModelCallOptions::builder()
+            .messages(vec![types::Message::user("hi")])
+            .build()
+            .unwrap();
+
+        let request = serde_json::to_value(openai.chat_request(options, true)).unwrap();
+
+        assert!(!openai.supports_structured_output());
+        assert_eq!(request["max_completion_tokens"], 100);
+        assert!(request.get("max_tokens").is_none());
+        assert!(request.get("stream_options").is_none());
+    }
+
+    #[test]
+    fn test_to_finish_reason() {
+        assert_eq!(to_finish_reason(Some("length")), FinishReason::Length);
+        assert_eq!(
+            to_finish_reason(Some("content_filter")),
+            FinishReason::ContentFilter
+        );
+        assert_eq!(
+            to_finish_reason(Some("unknown")),
+            FinishReason::Other("unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_openai_response_format() {
+        let format = to_openai_response_format(ResponseFormat::Json {
+            schema: Some(serde_json::json!({"type": "object"})),
+            name: Some("Vec<Item>".to_string()),
+            description: None,
+        });
+
+        assert_eq!(
+            serde_json::to_value(format).unwrap(),
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "Vec_Item_",
+                    "schema": {"type": "object", "additionalProperties": false},
+                    "strict": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_openai_response_format_makes_schemas_strict() {
+        let format = to_openai_response_format(ResponseFormat::Json {
+            schema: Some(serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string"},
+                    "nickname": {"type": ["string", "null"]},
+                    "age": {"type": "integer"},
+                    "pet": {"$ref": "#/$defs/Pet"}
+                },
+                "required": ["name"],
+                "$defs": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {"kind": {"type": "string", "enum": ["cat", "dog"]}}
+                    }
+                }
+            })),
+            name: Some("Person".to_string()),
+            description: None,
+        });
+
+        assert_eq!(
+            serde_json::to_value(format).unwrap(),
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "Person",
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string"},
+                            "nickname": {"type": ["string", "null"]},
+                            "age": {"type": ["integer", "null"]},
+                            "pet": {"anyOf": [{"$ref": "#/$defs/Pet"}, {"type": "null"}]}
+                        },
+                        "required": ["age", "name", "nickname", "pet"],
+                        "additionalProperties": false,
+                        "$defs": {
+                            "Pet": {
+                                "type": "object",
+                                "properties": {
+                                    "kind": {
+                                        "anyOf": [
+                                            {"type": "string", "enum": ["cat", "dog"]},
+                                            {"type": "null"}
+                                        ]
+                                    }
+                                },
+                                "required": ["kind"],
+                                "additionalProperties": false
+                            }
+                        }
+                    },
+                    "strict": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_stream_state_converts_chunks() {
+        let chunk: ChatCompletionChunk = serde_json::from_str(
+            r#"{"id":"1","model":"gpt-4o","usage":{"prompt_tokens":3,"completion_tokens":2,"total_tokens":5,
+                "prompt_tokens_details":{"cached_tokens":1}},
+                "choices":[{"index":0,"delta":{"content":"Hi"},"finish_reason":"stop"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            StreamState::default().process(chunk),
+            vec![
+                LanguageModelStreamChunk::TextDelta("Hi".to_string()),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::Stop,
+                },
+                LanguageModelStreamChunk::Usage(Usage {
+                    prompt_tokens: 3,
+                    completion_tokens: 2,
+                    total_tokens: 5,
+                    cached_tokens: Some(1),
+                    reasoning_tokens: None,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_state_assembles_tool_calls() {
+        let chunks = [
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[
+                {"index":0,"id":"call_1","type":"function","function":{"name":"get_weather","arguments":""}}
+            ]},"finish_reason":null}]}"#,
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[
+                {"index":0,"function":{"arguments":"{\"city\":"}}
+            ]},"finish_reason":null}]}"#,
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[
+                {"index":0,"function":{"arguments":"\"Paris\"}"}}
+            ]},"finish_reason":null}]}"#,
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{},"finish_reason":"tool_calls"}]}"#,
+        ];
+
+        let mut state = StreamState::default();
+        let output: Vec<_> = chunks
+            .iter()
+            .flat_map(|chunk| state.process(serde_json::from_str(chunk).unwrap()))
+            .collect();
+
+        assert_eq!(
+            output,
+            vec![
+                LanguageModelStreamChunk::ToolCall(ToolCall {
+                    id: "call_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: serde_json::json!({"city": "Paris"}),
+                }),
+                LanguageModelStreamChunk::Finish {
+                    finish_reason: FinishReason::ToolCalls,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_state_keeps_malformed_arguments() {
+        let chunks = [
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{"tool_calls":[
+                {"index":0,"id":"call_1","type":"function","function":{"name":"get_weather","arguments":"{\"city\":"}}
+            ]},"finish_reason":null}]}"#,
+            r#"{"id":"1","model":"gpt-4o","choices":[{"index":0,"delta":{},"finish_reason":"tool_calls"}]}"#,
+        ];
+
+        let mut state = StreamState::default();
+        let output: Vec<_> = chunks
+            .iter()
+            .flat_map(|chunk| state.process(serde_json::from_str(chunk).unwrap()))
+            .collect();
+
+        assert_eq!(
+            output[0],
+            LanguageModelStreamChunk::ToolCall(ToolCall {
+                id: "call_1".to_string(),
+                name: "get_weather".to_string(),
+                arguments: serde_json::json!("{\"city\":"),
+            })
+        );
+    }
 }
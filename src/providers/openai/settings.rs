@@ -1,19 +1,48 @@
 //! Defines the settings for the OpenAI provider.
 
+use std::collections::BTreeMap;
+
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
+use crate::core::retry::RetryPolicy;
 use crate::error::Error;
 
+/// The base URL of the OpenAI API.
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+
 // TODO: improve the settings types to fully match the OpenAI API
 /// Settings for the OpenAI provider.
 #[derive(Debug, Clone, Builder, Serialize, Deserialize)]
 #[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
 pub struct OpenAIProviderSettings {
-    /// The API key for the OpenAI API.
-    #[builder(default = "std::env::var(\"OPENAI_API_KEY\").unwrap_or_default()")]
+    /// The API key for the OpenAI API. No `Authorization` header is sent when
+    /// it is empty, which suits local OpenAI-compatible servers.
+    ///
+    /// Defaults to the `OPENAI_API_KEY` environment variable when the base URL
+    /// is OpenAI's, and to no key otherwise, so that the OpenAI key is never
+    /// sent to another vendor.
+    #[builder(default = "self.default_api_key()")]
     pub api_key: String,
 
+    /// The base URL of the API. Point it at any OpenAI-compatible endpoint,
+    /// such as a vLLM, llama.cpp or LM Studio server, Groq, Together or
+    /// OpenRouter.
+    #[builder(default = "OPENAI_BASE_URL.to_string()")]
+    pub base_url: String,
+
+    /// Additional headers sent with every request.
+    #[builder(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Additional query parameters sent with every request.
+    #[builder(default)]
+    pub query_params: BTreeMap<String, String>,
+
+    /// The OpenAI API features the endpoint supports.
+    #[builder(default)]
+    pub compatibility: Compatibility,
+
     /// The model to use for text generation.
     #[builder(default = "\"gpt-4o\".to_string()")]
     pub model_name: String,
@@ -25,6 +54,12 @@ pub struct OpenAIProviderSettings {
     /// The maximum number of tokens to generate.
     #[builder(default = "100")]
     pub max_tokens: u32,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout. Applies to the models created from this provider as
+    /// well.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
 }
 
 impl OpenAIProviderSettings {
@@ -33,3 +68,218 @@ impl OpenAIProviderSettings {
         OpenAIProviderSettingsBuilder::default()
     }
 }
+
+/// Returns the key from the environment if the base URL is OpenAI's, and an
+/// empty key otherwise.
+fn default_api_key(base_url: Option<&str>, env_key: Option<String>) -> String {
+    match base_url {
+        None | Some(OPENAI_BASE_URL) => env_key.unwrap_or_default(),
+        Some(_) => String::new(),
+    }
+}
+
+impl OpenAIProviderSettingsBuilder {
+    /// Reads `OPENAI_API_KEY` and picks the default key for the base URL.
+    fn default_api_key(&self) -> String {
+        default_api_key(
+            self.base_url.as_deref(),
+            std::env::var("OPENAI_API_KEY").ok(),
+        )
+    }
+
+    /// Adds a header sent with every request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds a query parameter sent with every request.
+    pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query_params
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+/// Toggles for the parts of the OpenAI API that OpenAI-compatible endpoints
+/// commonly implement differently or not at all.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Compatibility {
+    /// Whether the endpoint supports `json_schema` response formats. When it
+    /// does not, the schema of structured outputs is described in the prompt.
+    pub structured_outputs: bool,
+
+    /// Whether the endpoint accepts `stream_options` to report token usage at
+    /// the end of a stream.
+    pub stream_usage: bool,
+
+    /// Whether the token limit is sent as `max_completion_tokens`, which the
+    /// OpenAI reasoning models require, instead of `max_tokens`.
+    pub max_completion_tokens: bool,
+}
+
+impl Default for Compatibility {
+    fn default() -> Self {
+        Self {
+            structured_outputs: true,
+            stream_usage: true,
+            max_completion_tokens: false,
+        }
+    }
+}
+
+/// Settings for an OpenAI embedding model, created with
+/// [`OpenAI::embedding_model`](super::OpenAI::embedding_model).
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct OpenAIEmbeddingSettings {
+    /// The model to use for embeddings.
+    #[builder(default = "\"text-embedding-3-small\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"openai\".to_string()")]
+    pub provider_name: String,
+
+    /// The number of dimensions of the embeddings. Only supported by
+    /// `text-embedding-3` and later models. Uses the model's default when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub dimensions: Option<u32>,
+
+    /// The format the embeddings are transferred in.
+    #[builder(default)]
+    pub encoding_format: EncodingFormat,
+
+    /// The maximum number of values embedded in a single request.
+    #[builder(default = "2048", setter(into = false))]
+    pub max_embeddings_per_call: usize,
+}
+
+impl OpenAIEmbeddingSettings {
+    /// Creates a new builder for `OpenAIEmbeddingSettings`.
+    pub fn builder() -> OpenAIEmbeddingSettingsBuilder {
+        OpenAIEmbeddingSettingsBuilder::default()
+    }
+}
+
+/// The format embeddings are transferred in. Either way they are returned as
+/// floats, but base64 encoded responses are considerably smaller.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncodingFormat {
+    /// A JSON array of floats.
+    #[default]
+    Float,
+
+    /// A base64 encoded string of little-endian 32-bit floats.
+    Base64,
+}
+
+/// Settings for an OpenAI image model, created with
+/// [`OpenAI::image_model`](super::OpenAI::image_model).
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct OpenAIImageSettings {
+    /// The model to use for image generation.
+    #[builder(default = "\"dall-e-3\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"openai\".to_string()")]
+    pub provider_name: String,
+
+    /// The maximum number of images generated in a single request. Defaults
+    /// to 1 for `dall-e-3`, which supports no more, and 10 otherwise.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_images_per_call: Option<u32>,
+}
+
+impl OpenAIImageSettings {
+    /// Creates a new builder for `OpenAIImageSettings`.
+    pub fn builder() -> OpenAIImageSettingsBuilder {
+        OpenAIImageSettingsBuilder::default()
+    }
+}
+
+/// Settings for an OpenAI speech model, created with
+/// [`OpenAI::speech_model`](super::OpenAI::speech_model).
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct OpenAISpeechSettings {
+    /// The model to use for speech synthesis.
+    #[builder(default = "\"gpt-4o-mini-tts\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"openai\".to_string()")]
+    pub provider_name: String,
+
+    /// The voice used when the call options do not specify one.
+    #[builder(default = "\"alloy\".to_string()")]
+    pub voice: String,
+}
+
+impl OpenAISpeechSettings {
+    /// Creates a new builder for `OpenAISpeechSettings`.
+    pub fn builder() -> OpenAISpeechSettingsBuilder {
+        OpenAISpeechSettingsBuilder::default()
+    }
+}
+
+/// Settings for an OpenAI transcription model, created with
+/// [`OpenAI::transcription_model`](super::OpenAI::transcription_model).
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct OpenAITranscriptionSettings {
+    /// The model to use for transcription.
+    #[builder(default = "\"whisper-1\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"openai\".to_string()")]
+    pub provider_name: String,
+}
+
+impl OpenAITranscriptionSettings {
+    /// Creates a new builder for `OpenAITranscriptionSettings`.
+    pub fn builder() -> OpenAITranscriptionSettingsBuilder {
+        OpenAITranscriptionSettingsBuilder::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_key_defaults_to_env_only_for_openai() {
+        let env_key = || Some("sk-openai".to_string());
+
+        assert_eq!(default_api_key(None, env_key()), "sk-openai");
+        assert_eq!(
+            default_api_key(Some(OPENAI_BASE_URL), env_key()),
+            "sk-openai"
+        );
+        assert_eq!(
+            default_api_key(Some("https://api.groq.com/openai/v1"), env_key()),
+            ""
+        );
+        assert_eq!(default_api_key(None, None), "");
+
+        let groq = OpenAIProviderSettings::builder()
+            .base_url("https://api.groq.com/openai/v1")
+            .build()
+            .unwrap();
+        assert_eq!(groq.api_key, "");
+
+        let explicit = OpenAIProviderSettings::builder()
+            .base_url("https://api.groq.com/openai/v1")
+            .api_key("gsk-groq")
+            .build()
+            .unwrap();
+        assert_eq!(explicit.api_key, "gsk-groq");
+    }
+}
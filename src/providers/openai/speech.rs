@@ -0,0 +1,84 @@
+//! This module provides the OpenAI speech model, which implements the
+//! `SpeechModel` trait for the OpenAI `/audio/speech` endpoint.
+
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use serde::Serialize;
+
+use super::client::{OpenAIClient, SpeechRequest, SpeechResponse};
+use super::settings::OpenAISpeechSettings;
+use crate::{
+    core::{
+        speech_model::SpeechModel,
+        types::{AudioFormat, SpeechModelCallOptions, SpeechModelResponse, StreamSpeechResponse},
+    },
+    error::Result,
+};
+
+/// An OpenAI speech model.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenAISpeechModel {
+    #[serde(skip)]
+    client: OpenAIClient,
+    settings: OpenAISpeechSettings,
+}
+
+impl OpenAISpeechModel {
+    /// Creates a new `OpenAISpeechModel` that sends its requests with the
+    /// given client.
+    pub(crate) fn new(client: OpenAIClient, settings: OpenAISpeechSettings) -> Self {
+        Self { client, settings }
+    }
+
+    /// Sends a speech request and returns the media type of the audio along
+    /// with the stream of its bytes.
+    async fn speech(&self, options: SpeechModelCallOptions) -> Result<StreamSpeechResponse> {
+        let request = SpeechRequest {
+            model: self.settings.model_name.to_string(),
+            input: options.text,
+            voice: options
+                .voice
+                .unwrap_or_else(|| self.settings.voice.to_string()),
+            response_format: options.format,
+            speed: options.speed,
+            instructions: options.instructions,
+        };
+
+        let SpeechResponse {
+            content_type,
+            audio,
+        } = self.client.generate_speech(&request).await?;
+
+        // OpenAI responds with MP3 unless another format is requested.
+        let format = options.format.unwrap_or(AudioFormat::Mp3);
+        Ok(StreamSpeechResponse {
+            stream: audio,
+            media_type: content_type.unwrap_or_else(|| format.media_type().to_string()),
+        })
+    }
+}
+
+#[async_trait]
+impl SpeechModel for OpenAISpeechModel {
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    async fn generate(&self, options: SpeechModelCallOptions) -> Result<SpeechModelResponse> {
+        let response = self.speech(options).await?;
+        let chunks: Vec<Vec<u8>> = response.stream.try_collect().await?;
+
+        Ok(SpeechModelResponse {
+            audio: chunks.concat(),
+            media_type: response.media_type,
+        })
+    }
+
+    async fn stream(&self, options: SpeechModelCallOptions) -> Result<StreamSpeechResponse> {
+        self.speech(options).await
+    }
+}
@@ -0,0 +1,87 @@
+//! This module provides the OpenAI transcription model, which implements the
+//! `TranscriptionModel` trait for the OpenAI `/audio/transcriptions` endpoint.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use super::client::{OpenAIClient, TranscriptionRequest};
+use super::settings::OpenAITranscriptionSettings;
+use crate::{
+    core::{
+        media_type,
+        transcription_model::TranscriptionModel,
+        types::{TranscriptionModelCallOptions, TranscriptionModelResponse, TranscriptionSegment},
+    },
+    error::Result,
+};
+
+/// An OpenAI transcription model.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenAITranscriptionModel {
+    #[serde(skip)]
+    client: OpenAIClient,
+    settings: OpenAITranscriptionSettings,
+}
+
+impl OpenAITranscriptionModel {
+    /// Creates a new `OpenAITranscriptionModel` that sends its requests with
+    /// the given client.
+    pub(crate) fn new(client: OpenAIClient, settings: OpenAITranscriptionSettings) -> Self {
+        Self { client, settings }
+    }
+}
+
+#[async_trait]
+impl TranscriptionModel for OpenAITranscriptionModel {
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    async fn transcribe(
+        &self,
+        options: TranscriptionModelCallOptions,
+    ) -> Result<TranscriptionModelResponse> {
+        // OpenAI infers the audio format from the file name.
+        let extension = media_type::extension(&options.media_type).unwrap_or("mp3");
+
+        // Only the whisper models support the `verbose_json` format, which
+        // reports the language, duration and segments of the audio.
+        let verbose = self.settings.model_name.starts_with("whisper");
+        let (response_format, timestamp_granularities) = match verbose {
+            true => ("verbose_json", vec!["segment".to_string()]),
+            false => ("json", Vec::new()),
+        };
+
+        let request = TranscriptionRequest {
+            file: options.audio,
+            file_name: format!("audio.{extension}"),
+            media_type: options.media_type,
+            model: self.settings.model_name.to_string(),
+            response_format: response_format.to_string(),
+            timestamp_granularities,
+            language: options.language,
+            prompt: options.prompt,
+        };
+
+        let response = self.client.transcribe(&request).await?;
+
+        Ok(TranscriptionModelResponse {
+            text: response.text,
+            language: response.language,
+            duration: response.duration,
+            segments: response
+                .segments
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    text: segment.text,
+                    start: segment.start,
+                    end: segment.end,
+                })
+                .collect(),
+        })
+    }
+}
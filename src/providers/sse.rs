@@ -0,0 +1,80 @@
+//! A parser for server-sent events, shared by the providers that stream
+//! responses as `text/event-stream`.
+
+use futures::{StreamExt, stream, stream::BoxStream};
+
+use crate::error::{Error, Result};
+
+/// Returns a stream of the `data` payloads of the events in a
+/// `text/event-stream` response.
+pub fn data_stream(response: reqwest::Response) -> BoxStream<'static, Result<String>> {
+    let mut parser = SseParser::default();
+    response
+        .bytes_stream()
+        .map(move |bytes| match bytes {
+            Ok(bytes) => parser.feed(&bytes).into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(Error::from(err))],
+        })
+        .flat_map(stream::iter)
+        .boxed()
+}
+
+/// An incremental parser for `text/event-stream` responses.
+///
+/// Bytes are fed in as they arrive from the network and the `data` payload
+/// of every completed event is returned. Comments and fields other than
+/// `data` are ignored.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Feeds a chunk of bytes into the parser and returns the data of all
+    /// events completed by it.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = Vec::new();
+        while let Some(position) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=position).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(self.data.join("\n"));
+                    self.data.clear();
+                }
+            } else if let Some(value) = line.strip_prefix("data:") {
+                self.data
+                    .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_parser_handles_split_events() {
+        let mut parser = SseParser::default();
+
+        assert!(parser.feed(b"data: {\"a\"").is_empty());
+        assert_eq!(parser.feed(b":1}\n\ndata: [DONE]\n"), vec!["{\"a\":1}"]);
+        assert_eq!(parser.feed(b"\n"), vec!["[DONE]"]);
+    }
+
+    #[test]
+    fn test_sse_parser_ignores_comments_and_joins_multiline_data() {
+        let mut parser = SseParser::default();
+
+        let events = parser.feed(b": keep-alive\r\nevent: message\r\ndata: a\r\ndata: b\r\n\r\n");
+        assert_eq!(events, vec!["a\nb"]);
+    }
+}
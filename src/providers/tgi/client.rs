@@ -0,0 +1,393 @@
+//! This module provides a client for interacting with a Text Generation
+//! Inference server. It handles the construction of requests, sending them to
+//! the Messages API and the generate endpoints, and parsing the responses.
+
+use futures::{StreamExt, stream::BoxStream};
+use reqwest::Client;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use super::settings::Grammar;
+use crate::core::retry::RetryPolicy;
+use crate::error::{ApiErrorDetails, Error, Result};
+use crate::providers::sse;
+
+/// A client for a Text Generation Inference server.
+#[derive(Debug, Clone)]
+pub struct TgiClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl TgiClient {
+    /// Creates a new `TgiClient` for the server at the given base URL. The API
+    /// key is omitted from requests when it is empty.
+    pub fn new(api_key: String, base_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            api_key,
+            base_url,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends a request to the Messages API to generate a chat completion.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn chat(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<ChatResponse> {
+        let response = self
+            .send("/v1/chat/completions", request, retry_policy)
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a streaming request to the Messages API.
+    ///
+    /// The returned stream yields the parsed server-sent events until the
+    /// server signals the end of the stream.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn stream_chat(
+        &self,
+        request: &ChatRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<ChatChunk>>> {
+        let response = self
+            .send("/v1/chat/completions", request, retry_policy)
+            .await?;
+
+        let chunks = sse::data_stream(response)
+            .filter(|data| futures::future::ready(!matches!(data, Ok(data) if data == "[DONE]")))
+            .map(|data| data.and_then(|data| parse_event(&data)));
+
+        Ok(chunks.boxed())
+    }
+
+    /// Sends a request to the `/generate` endpoint to continue a prompt.
+    ///
+    /// The request is retried according to the given policy or, if unset,
+    /// the client's.
+    pub async fn generate(
+        &self,
+        request: &GenerateRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<GenerateResponse> {
+        let response = self.send("/generate", request, retry_policy).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sends a request to the `/generate_stream` endpoint to continue a
+    /// prompt, yielding the generated tokens as they arrive.
+    ///
+    /// Only the initial request is retried, according to the given policy
+    /// or, if unset, the client's.
+    pub async fn generate_stream(
+        &self,
+        request: &GenerateRequest,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<BoxStream<'static, Result<StreamResponse>>> {
+        let response = self.send("/generate_stream", request, retry_policy).await?;
+
+        let tokens =
+            sse::data_stream(response).map(|data| data.and_then(|data| parse_event(&data)));
+
+        Ok(tokens.boxed())
+    }
+
+    /// Sends a request, retrying it according to the given policy or, if
+    /// unset, the client's.
+    async fn send(
+        &self,
+        path: &str,
+        request: &impl Serialize,
+        retry_policy: Option<&RetryPolicy>,
+    ) -> Result<reqwest::Response> {
+        retry_policy
+            .unwrap_or(&self.retry_policy)
+            .retry(|| async {
+                let mut builder = self.client.post(format!("{}{path}", self.base_url));
+                if !self.api_key.is_empty() {
+                    builder = builder.bearer_auth(&self.api_key);
+                }
+                let response = builder.json(request).send().await?;
+
+                if !response.status().is_success() {
+                    return Err(Error::from_response(response).await);
+                }
+
+                Ok(response)
+            })
+            .await
+    }
+}
+
+/// Parses the data of a server-sent event. TGI reports errors that occur
+/// mid-stream as an event with an `error` field.
+fn parse_event<T: DeserializeOwned>(data: &str) -> Result<T> {
+    serde_json::from_str(data).map_err(|err| {
+        let value: serde_json::Value = serde_json::from_str(data).unwrap_or_default();
+        match value.get("error") {
+            Some(_) => Error::from_api_error(ApiErrorDetails::parse(None, data), None),
+            None => Error::from(err),
+        }
+    })
+}
+
+/// A request to the Messages API.
+#[derive(Debug, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<Grammar>,
+    pub stream: bool,
+}
+
+/// A message in a chat conversation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Message {
+    pub role: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+/// A tool that the model may call.
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub function: FunctionDefinition,
+}
+
+/// The definition of a function that the model may call.
+#[derive(Debug, Serialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool call generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "function_kind")]
+    pub kind: String,
+    pub function: FunctionCall,
+}
+
+fn function_kind() -> String {
+    "function".to_string()
+}
+
+/// The function that the model called.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments to call the function with. Older TGI versions return
+    /// them as a JSON object rather than a JSON encoded string.
+    pub arguments: serde_json::Value,
+}
+
+/// A response from the Messages API.
+#[derive(Debug, Deserialize)]
+pub struct ChatResponse {
+    pub model: String,
+    pub choices: Vec<ChatChoice>,
+    pub usage: Option<UsageInfo>,
+}
+
+/// A choice in a chat completion response.
+#[derive(Debug, Deserialize)]
+pub struct ChatChoice {
+    pub index: u32,
+    pub message: Message,
+    pub finish_reason: Option<String>,
+}
+
+/// Token usage reported by the Messages API.
+#[derive(Debug, Deserialize)]
+pub struct UsageInfo {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// A chunk of a streamed chat completion.
+#[derive(Debug, Deserialize)]
+pub struct ChatChunk {
+    pub model: String,
+    pub choices: Vec<ChatChunkChoice>,
+    pub usage: Option<UsageInfo>,
+}
+
+/// A choice in a streamed chat completion chunk.
+#[derive(Debug, Deserialize)]
+pub struct ChatChunkChoice {
+    pub index: u32,
+    #[serde(default)]
+    pub delta: ChatDelta,
+    pub finish_reason: Option<String>,
+}
+
+/// The incremental message content of a streamed choice.
+#[derive(Debug, Default, Deserialize)]
+pub struct ChatDelta {
+    pub role: Option<String>,
+    pub content: Option<String>,
+    pub tool_calls: Option<Vec<ToolCallDelta>>,
+}
+
+/// A fragment of a tool call in a streamed choice.
+#[derive(Debug, Deserialize)]
+pub struct ToolCallDelta {
+    pub index: u32,
+    pub id: Option<String>,
+    pub function: Option<FunctionCallDelta>,
+}
+
+/// A fragment of a function call in a streamed choice.
+#[derive(Debug, Deserialize)]
+pub struct FunctionCallDelta {
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
+/// A request to the `/generate` and `/generate_stream` endpoints.
+#[derive(Debug, Serialize)]
+pub struct GenerateRequest {
+    pub inputs: String,
+    pub parameters: GenerateParameters,
+}
+
+/// The parameters of a generate request.
+#[derive(Debug, Default, Serialize)]
+pub struct GenerateParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_new_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repetition_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<Grammar>,
+    pub details: bool,
+    pub return_full_text: bool,
+}
+
+/// A response from the `/generate` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct GenerateResponse {
+    pub generated_text: String,
+    pub details: Option<Details>,
+}
+
+/// The details of a generation, returned when they are requested.
+#[derive(Debug, Deserialize)]
+pub struct Details {
+    pub finish_reason: String,
+    pub generated_tokens: u32,
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub tokens: Vec<Token>,
+}
+
+/// A generated token.
+#[derive(Debug, Deserialize)]
+pub struct Token {
+    pub id: u32,
+    pub text: String,
+    pub logprob: Option<f32>,
+    pub special: bool,
+}
+
+/// An event of a `/generate_stream` response.
+#[derive(Debug, Deserialize)]
+pub struct StreamResponse {
+    pub index: u32,
+    pub token: Token,
+    /// The complete generated text, sent with the last token only.
+    pub generated_text: Option<String>,
+    /// The details of the generation, sent with the last token when they are
+    /// requested.
+    pub details: Option<StreamDetails>,
+}
+
+/// The details of a streamed generation.
+#[derive(Debug, Deserialize)]
+pub struct StreamDetails {
+    pub finish_reason: String,
+    pub generated_tokens: u32,
+    pub seed: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_grammar() {
+        let grammar = Grammar::Json(json!({"type": "object"}));
+        assert_eq!(
+            serde_json::to_value(&grammar).unwrap(),
+            json!({"type": "json", "value": {"type": "object"}})
+        );
+        assert_eq!(
+            serde_json::to_value(Grammar::Regex(r"\d+".to_string())).unwrap(),
+            json!({"type": "regex", "value": r"\d+"})
+        );
+    }
+
+    #[test]
+    fn test_parse_stream_error() {
+        let error = parse_event::<StreamResponse>(
+            r#"{"error":"Input validation error","error_type":"validation"}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(error, Error::InvalidRequestError(_)));
+    }
+}
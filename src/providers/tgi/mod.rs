@@ -0,0 +1,491 @@
+//! This module provides the Text Generation Inference provider, which
+//! implements the `LanguageModel` and `Provider` traits for self-hosted
+//! Hugging Face TGI servers.
+//!
+//! Text is generated with the Messages API by default, or with the
+//! `/generate` endpoints when [`TgiApi::Generate`] is selected, which also
+//! accept TGI-specific parameters such as the repetition penalty.
+
+pub mod client;
+pub mod settings;
+
+pub use settings::{Grammar, TgiApi, TgiProviderSettings};
+
+use self::client::{
+    ChatChunk, ChatRequest, FunctionCall, FunctionDefinition, GenerateParameters, GenerateRequest,
+    Message, StreamResponse, TgiClient, Tool, UsageInfo,
+};
+use crate::{
+    core::{
+        language_model::LanguageModel,
+        provider::Provider,
+        types::{
+            self, FinishReason, LanguageModelCallOptions, LanguageModelResponse,
+            LanguageModelStream, LanguageModelStreamChunk, ResponseFormat, ToolCall, Usage,
+        },
+    },
+    error::{Error, Result},
+    providers::{parse_tool_arguments, user_text},
+};
+use async_trait::async_trait;
+use futures::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The Text Generation Inference provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct Tgi {
+    #[serde(skip)]
+    client: TgiClient,
+    settings: TgiProviderSettings,
+}
+
+impl Tgi {
+    /// Creates a new `Tgi` provider with the given settings.
+    pub fn new(settings: TgiProviderSettings) -> Self {
+        let client = TgiClient::new(settings.api_key.to_string(), settings.base_url.to_string())
+            .with_retry_policy(settings.retry_policy.clone());
+        Self { client, settings }
+    }
+
+    /// Returns the grammar of the response, preferring the response format of
+    /// the call options over the one in the settings.
+    fn grammar(&self, response_format: Option<ResponseFormat>) -> Option<Grammar> {
+        match response_format {
+            Some(ResponseFormat::Json { schema, .. }) => Some(Grammar::Json(
+                schema.unwrap_or_else(|| serde_json::json!({"type": "object"})),
+            )),
+            Some(ResponseFormat::Text) | None => self.settings.grammar.clone(),
+        }
+    }
+
+    /// Builds a Messages API request from the provider-neutral call options.
+    fn chat_request(&self, options: LanguageModelCallOptions, stream: bool) -> Result<ChatRequest> {
+        if options.settings.top_k.is_some() {
+            log::warn!("The TGI Messages API does not support `top_k`, ignoring it");
+        }
+        if self.settings.repetition_penalty.is_some() || self.settings.details {
+            log::warn!(
+                "The TGI Messages API does not support `repetition_penalty` and `details`, ignoring them"
+            );
+        }
+
+        let tools = options
+            .tools
+            .into_iter()
+            .map(|tool| Tool {
+                kind: "function".to_string(),
+                function: FunctionDefinition {
+                    name: tool.name,
+                    description: tool.description,
+                    parameters: tool.parameters,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ChatRequest {
+            model: self.model_name().to_string(),
+            messages: to_tgi_messages(options.system, options.messages)?,
+            max_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+            temperature: options.settings.temperature,
+            top_p: options.settings.top_p,
+            presence_penalty: options.settings.presence_penalty,
+            frequency_penalty: options.settings.frequency_penalty,
+            stop: (!options.settings.stop_sequences.is_empty())
+                .then_some(options.settings.stop_sequences),
+            seed: options.settings.seed,
+            tools: (!tools.is_empty()).then_some(tools),
+            response_format: self.grammar(options.response_format),
+            stream,
+        })
+    }
+
+    /// Builds a generate request from the provider-neutral call options.
+    fn generate_request(&self, options: LanguageModelCallOptions) -> Result<GenerateRequest> {
+        if !options.tools.is_empty() {
+            log::warn!("The TGI generate API does not support tools, ignoring them");
+        }
+        if options.settings.presence_penalty.is_some() {
+            log::warn!("The TGI generate API does not support `presence_penalty`, ignoring it");
+        }
+
+        Ok(GenerateRequest {
+            inputs: to_prompt(options.system, options.messages)?,
+            parameters: GenerateParameters {
+                max_new_tokens: options.settings.max_tokens.or(self.settings.max_tokens),
+                temperature: options.settings.temperature,
+                top_p: options.settings.top_p,
+                top_k: options.settings.top_k,
+                repetition_penalty: self.settings.repetition_penalty,
+                frequency_penalty: options.settings.frequency_penalty,
+                stop: options.settings.stop_sequences,
+                seed: options.settings.seed,
+                grammar: self.grammar(options.response_format),
+                details: self.settings.details,
+                return_full_text: false,
+            },
+        })
+    }
+}
+
+impl Provider for Tgi {}
+
+#[async_trait]
+impl LanguageModel for Tgi {
+    fn provider_name(&self) -> &str {
+        &self.settings.provider_name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.settings.model_name
+    }
+
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn generate(&self, options: LanguageModelCallOptions) -> Result<LanguageModelResponse> {
+        let retry_policy = options.settings.retry_policy.clone();
+        if self.settings.api == TgiApi::Generate {
+            let request = self.generate_request(options)?;
+            let response = self
+                .client
+                .generate(&request, retry_policy.as_ref())
+                .await?;
+            let details = response.details;
+
+            return Ok(LanguageModelResponse {
+                text: response.generated_text,
+                model: Some(self.model_name().to_string()),
+                tool_calls: vec![],
+                finish_reason: to_finish_reason(details.as_ref().map(|d| d.finish_reason.as_str())),
+                usage: None,
+            });
+        }
+
+        let request = self.chat_request(options, false)?;
+
+        let response = self.client.chat(&request, retry_policy.as_ref()).await?;
+        let choice = response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("response contained no choices".to_string()))?;
+        let message = choice.message;
+
+        let tool_calls: Vec<_> = message
+            .tool_calls
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_tool_call)
+            .collect();
+
+        Ok(LanguageModelResponse {
+            text: message.content.unwrap_or_default(),
+            model: Some(response.model),
+            finish_reason: if tool_calls.is_empty() {
+                to_finish_reason(choice.finish_reason.as_deref())
+            } else {
+                FinishReason::ToolCalls
+            },
+            tool_calls,
+            usage: response.usage.map(to_usage),
+        })
+    }
+
+    async fn stream(&self, options: LanguageModelCallOptions) -> Result<LanguageModelStream> {
+        let retry_policy = options.settings.retry_policy.clone();
+        if self.settings.api == TgiApi::Generate {
+            let request = self.generate_request(options)?;
+            let tokens = self
+                .client
+                .generate_stream(&request, retry_policy.as_ref())
+                .await?;
+
+            return Ok(tokens
+                .map(|token| match token {
+                    Ok(token) => process_token(token),
+                    Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+                })
+                .flat_map(stream::iter)
+                .boxed());
+        }
+
+        let request = self.chat_request(options, true)?;
+
+        let chunks = self
+            .client
+            .stream_chat(&request, retry_policy.as_ref())
+            .await?;
+
+        let mut state = StreamState::default();
+        Ok(chunks
+            .map(move |chunk| match chunk {
+                Ok(chunk) => state.process(chunk),
+                Err(err) => vec![LanguageModelStreamChunk::Error(err.to_string())],
+            })
+            .flat_map(stream::iter)
+            .boxed())
+    }
+}
+
+/// Converts a TGI finish reason into a normalized finish reason. The generate
+/// API only reports one when details are requested.
+fn to_finish_reason(reason: Option<&str>) -> FinishReason {
+    match reason {
+        None | Some("stop" | "eos_token" | "stop_sequence") => FinishReason::Stop,
+        Some("length") => FinishReason::Length,
+        Some("tool_calls") => FinishReason::ToolCalls,
+        Some(other) => FinishReason::Other(other.to_string()),
+    }
+}
+
+/// Converts TGI token usage into normalized token usage.
+fn to_usage(usage: UsageInfo) -> Usage {
+    Usage {
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+        ..Default::default()
+    }
+}
+
+/// Converts a TGI tool call into a tool call, accepting the arguments either
+/// as a JSON encoded string or as a JSON object.
+fn to_tool_call(call: client::ToolCall) -> ToolCall {
+    let arguments = match call.function.arguments {
+        Value::String(arguments) => parse_tool_arguments(&arguments),
+        arguments => arguments,
+    };
+    ToolCall {
+        id: call.id,
+        name: call.function.name,
+        arguments,
+    }
+}
+
+/// Converts a streamed token of the generate API into provider-neutral stream
+/// chunks. Special tokens, such as the end of sequence token, are skipped.
+fn process_token(token: StreamResponse) -> Vec<LanguageModelStreamChunk> {
+    let mut chunks = Vec::new();
+    if !token.token.special && !token.token.text.is_empty() {
+        chunks.push(LanguageModelStreamChunk::TextDelta(token.token.text));
+    }
+    if token.generated_text.is_some() {
+        chunks.push(LanguageModelStreamChunk::Finish {
+            finish_reason: to_finish_reason(
+                token.details.as_ref().map(|d| d.finish_reason.as_str()),
+            ),
+        });
+    }
+    chunks
+}
+
+/// A tool call whose fragments are still being streamed.
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// Converts streamed Messages API chunks into provider-neutral stream chunks,
+/// assembling tool call fragments into complete calls.
+#[derive(Debug, Default)]
+struct StreamState {
+    tool_calls: BTreeMap<u32, PendingToolCall>,
+}
+
+impl StreamState {
+    fn process(&mut self, chunk: ChatChunk) -> Vec<LanguageModelStreamChunk> {
+        let mut chunks = Vec::new();
+        for choice in chunk.choices {
+            if let Some(content) = choice.delta.content.filter(|c| !c.is_empty()) {
+                chunks.push(LanguageModelStreamChunk::TextDelta(content));
+            }
+            for delta in choice.delta.tool_calls.unwrap_or_default() {
+                let pending = self.tool_calls.entry(delta.index).or_default();
+                if let Some(id) = delta.id {
+                    pending.id = id;
+                }
+                if let Some(function) = delta.function {
+                    pending.name.push_str(&function.name.unwrap_or_default());
+                    pending
+                        .arguments
+                        .push_str(&function.arguments.unwrap_or_default());
+                }
+            }
+            if let Some(finish_reason) = choice.finish_reason {
+                let has_tool_calls = !self.tool_calls.is_empty();
+                for (_, call) in std::mem::take(&mut self.tool_calls) {
+                    chunks.push(LanguageModelStreamChunk::ToolCall(ToolCall {
+                        id: call.id,
+                        name: call.name,
+                        arguments: parse_tool_arguments(&call.arguments),
+                    }));
+                }
+                chunks.push(LanguageModelStreamChunk::Finish {
+                    finish_reason: if has_tool_calls {
+                        FinishReason::ToolCalls
+                    } else {
+                        to_finish_reason(Some(&finish_reason))
+                    },
+                });
+            }
+        }
+        if let Some(usage) = chunk.usage {
+            chunks.push(LanguageModelStreamChunk::Usage(to_usage(usage)));
+        }
+        chunks
+    }
+}
+
+/// Converts the provider-neutral conversation into Messages API messages,
+/// placing the system prompt first.
+fn to_tgi_messages(system: Option<String>, messages: Vec<types::Message>) -> Result<Vec<Message>> {
+    let message = |role: &str, content: Option<String>| Message {
+        role: role.to_string(),
+        content,
+        tool_calls: None,
+        tool_call_id: None,
+    };
+
+    let system = system.map(types::Message::system);
+    system
+        .into_iter()
+        .chain(messages)
+        .map(|msg| {
+            Ok(match msg {
+                types::Message::System { content } => message("system", Some(content)),
+                types::Message::User { content } => {
+                    message("user", Some(user_text("TGI", content)?))
+                }
+                types::Message::Assistant {
+                    content,
+                    tool_calls,
+                } if !tool_calls.is_empty() => {
+                    let tool_calls = tool_calls
+                        .into_iter()
+                        .map(|call| client::ToolCall {
+                            id: call.id,
+                            kind: "function".to_string(),
+                            function: FunctionCall {
+                                name: call.name,
+                                arguments: Value::String(call.arguments.to_string()),
+                            },
+                        })
+                        .collect();
+                    Message {
+                        tool_calls: Some(tool_calls),
+                        ..message("assistant", (!content.is_empty()).then_some(content))
+                    }
+                }
+                types::Message::Assistant { content, .. } => message("assistant", Some(content)),
+                types::Message::Tool {
+                    tool_call_id,
+                    content,
+                    ..
+                } => Message {
+                    tool_call_id: Some(tool_call_id),
+                    ..message("tool", Some(content))
+                },
+            })
+        })
+        .collect()
+}
+
+/// Renders the provider-neutral conversation as a plain text prompt for the
+/// generate API, separating the messages with blank lines. Tool calls are
+/// left out, as the generate API has no notion of them.
+fn to_prompt(system: Option<String>, messages: Vec<types::Message>) -> Result<String> {
+    let system = system.map(types::Message::system);
+    let contents = system
+        .into_iter()
+        .chain(messages)
+        .map(|message| match message {
+            types::Message::User { content } => user_text("TGI", content),
+            types::Message::System { content }
+            | types::Message::Assistant { content, .. }
+            | types::Message::Tool { content, .. } => Ok(content),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(contents
+        .into_iter()
+        .filter(|content| !content.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ContentPart;
+    use crate::providers::tgi::client::{StreamDetails, Token};
+
+    #[test]
+    fn test_to_prompt_joins_messages() {
+        let prompt = to_prompt(
+            Some("You are a poet.".to_string()),
+            vec![
+                types::Message::user("Write a haiku."),
+                types::Message::assistant(""),
+            ],
+        )
+        .unwrap();
+        assert_eq!(prompt, "You are a poet.\n\nWrite a haiku.");
+    }
+
+    #[test]
+    fn test_to_prompt_rejects_non_text_parts() {
+        let result = to_prompt(
+            None,
+            vec![types::Message::user_with_parts([
+                ContentPart::text("Describe this image."),
+                ContentPart::image_url("https://example.com/cat.png"),
+            ])],
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedContentError(message))
+                if message == "TGI provider does not yet support image content parts in this SDK"
+        ));
+    }
+
+    #[test]
+    fn test_process_token_skips_special_tokens() {
+        let token = |text: &str, special: bool| Token {
+            id: 0,
+            text: text.to_string(),
+            logprob: None,
+            special,
+        };
+
+        assert_eq!(
+            process_token(StreamResponse {
+                index: 1,
+                token: token("Hi", false),
+                generated_text: None,
+                details: None,
+            }),
+            vec![LanguageModelStreamChunk::TextDelta("Hi".to_string())]
+        );
+        assert_eq!(
+            process_token(StreamResponse {
+                index: 2,
+                token: token("</s>", true),
+                generated_text: Some("Hi".to_string()),
+                details: Some(StreamDetails {
+                    finish_reason: "length".to_string(),
+                    generated_tokens: 2,
+                    seed: None,
+                }),
+            }),
+            vec![LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Length,
+            }]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Defines the settings for the Text Generation Inference provider.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::core::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Settings for the Text Generation Inference provider.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+#[builder(pattern = "owned", setter(into), build_fn(error = "Error"))]
+pub struct TgiProviderSettings {
+    /// The token sent as a bearer token with every request. No
+    /// `Authorization` header is sent when it is empty.
+    #[builder(default = "std::env::var(\"HF_TOKEN\").unwrap_or_default()")]
+    pub api_key: String,
+
+    /// The model name reported by the provider. A TGI server serves a single
+    /// model, so it is not used to route requests.
+    #[builder(default = "\"tgi\".to_string()")]
+    pub model_name: String,
+
+    /// The name of the provider.
+    #[builder(default = "\"tgi\".to_string()")]
+    pub provider_name: String,
+
+    /// The base URL of the TGI server.
+    #[builder(default = "\"http://localhost:8080\".to_string()")]
+    pub base_url: String,
+
+    /// The API used to generate text.
+    #[builder(default)]
+    pub api: TgiApi,
+
+    /// The maximum number of tokens to generate. Uses the server's default
+    /// when unset.
+    #[builder(default, setter(into = false, strip_option))]
+    pub max_tokens: Option<u32>,
+
+    /// The penalty for repeated tokens, where `1.0` means no penalty. Only
+    /// supported by the generate API.
+    #[builder(default, setter(into = false, strip_option))]
+    pub repetition_penalty: Option<f32>,
+
+    /// Whether the generate API returns the details of the generation, which
+    /// include the reason it finished. Only supported by the generate API.
+    #[builder(default)]
+    pub details: bool,
+
+    /// The grammar the generated text must follow. A JSON response format in
+    /// the call options takes precedence over it.
+    #[builder(default, setter(strip_option))]
+    pub grammar: Option<Grammar>,
+
+    /// The policy for retrying requests that fail with a rate limit, server
+    /// error or timeout.
+    #[builder(default)]
+    pub retry_policy: RetryPolicy,
+}
+
+impl TgiProviderSettings {
+    /// Creates a new builder for `TgiProviderSettings`.
+    pub fn builder() -> TgiProviderSettingsBuilder {
+        TgiProviderSettingsBuilder::default()
+    }
+}
+
+/// The TGI APIs that text can be generated with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TgiApi {
+    /// The OpenAI-compatible Messages API at `/v1/chat/completions`, which
+    /// applies the model's chat template and supports tool calls.
+    #[default]
+    Messages,
+
+    /// The `/generate` and `/generate_stream` endpoints, which continue a raw
+    /// prompt. The conversation is rendered as plain text, so this suits base
+    /// models and custom prompt formats.
+    Generate,
+}
+
+/// A grammar that constrains the generated text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Grammar {
+    /// A JSON value matching the given JSON Schema.
+    Json(serde_json::Value),
+
+    /// Text matching the given regular expression.
+    Regex(String),
+}
@@ -0,0 +1,191 @@
+//! Integration tests for the Anthropic provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, RetryPolicy,
+        StreamTextCallOptions, generate_text, stream_text,
+    },
+    providers::anthropic::{Anthropic, AnthropicProviderSettings},
+};
+use futures::StreamExt;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path},
+};
+
+fn anthropic(server: &MockServer) -> Anthropic {
+    let settings = AnthropicProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(server.uri())
+        .build()
+        .expect("Failed to build AnthropicProviderSettings");
+
+    Anthropic::new(settings)
+}
+
+#[tokio::test]
+async fn test_generate_text_with_anthropic() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("anthropic-version", "2023-06-01"))
+        .and(body_partial_json(json!({
+            "system": "be brief",
+            "messages": [{"role": "user", "content": [{"type": "text", "text": "Say hello"}]}],
+            "max_tokens": 1024
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5",
+            "content": [{"type": "text", "text": "hello"}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 12, "output_tokens": 2}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .system("be brief")
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(anthropic(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_generate_text_retries_overloaded_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(529).set_body_json(json!({
+            "type": "error",
+            "error": {"type": "overloaded_error", "message": "Overloaded"}
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5",
+            "content": [{"type": "text", "text": "hello"}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 12, "output_tokens": 2}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .retry_policy(
+            RetryPolicy::builder()
+                .base_delay(Duration::from_millis(10))
+                .build()
+                .expect("Failed to build RetryPolicy"),
+        )
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(anthropic(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_api_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "type": "error",
+            "error": {"type": "authentication_error", "message": "invalid x-api-key"}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(anthropic(&server), options).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_stream_text_with_anthropic() {
+    let events = [
+        json!({"type": "message_start", "message": {"id": "msg_1", "model": "claude-sonnet-4-5",
+            "usage": {"input_tokens": 12, "output_tokens": 1}}}),
+        json!({"type": "content_block_start", "index": 0, "content_block": {"type": "text", "text": ""}}),
+        json!({"type": "ping"}),
+        json!({"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta", "text": "hel"}}),
+        json!({"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta", "text": "lo"}}),
+        json!({"type": "content_block_stop", "index": 0}),
+        json!({"type": "message_delta", "delta": {"stop_reason": "end_turn"}, "usage": {"output_tokens": 2}}),
+        json!({"type": "message_stop"}),
+    ];
+    let body: String = events
+        .iter()
+        .map(|event| {
+            format!(
+                "event: {}\ndata: {event}\n\n",
+                event["type"].as_str().unwrap()
+            )
+        })
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(anthropic(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    let LanguageModelStreamChunk::Usage(usage) = &chunks[3] else {
+        panic!("Expected usage, got {:?}", chunks[3]);
+    };
+    assert_eq!(usage.prompt_tokens, 12);
+    assert_eq!(usage.completion_tokens, 2);
+}
@@ -0,0 +1,171 @@
+//! Integration tests for the Azure OpenAI provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, StreamTextCallOptions,
+        generate_text, stream_text,
+    },
+    providers::azure::{Azure, AzureProviderSettings},
+};
+use futures::StreamExt;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path, query_param},
+};
+
+const DEPLOYMENT_PATH: &str = "/openai/deployments/gpt-4o-prod/chat/completions";
+
+fn azure(server: &MockServer) -> Azure {
+    let settings = AzureProviderSettings::builder()
+        .endpoint(server.uri())
+        .deployment_name("gpt-4o-prod")
+        .api_key("test-key")
+        .build()
+        .expect("Failed to build AzureProviderSettings");
+
+    Azure::new(settings)
+}
+
+fn completion(content: &str, finish_reason: &str, filtered: bool) -> Value {
+    json!({
+        "id": "chatcmpl-1",
+        "object": "chat.completion",
+        "created": 1,
+        "model": "gpt-4o-2024-08-06",
+        "prompt_filter_results": [{"prompt_index": 0, "content_filter_results": {}}],
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": content},
+            "finish_reason": finish_reason,
+            "content_filter_results": {
+                "hate": {"filtered": false, "severity": "safe"},
+                "violence": {"filtered": filtered, "severity": if filtered { "high" } else { "safe" }}
+            }
+        }],
+        "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}
+    })
+}
+
+#[tokio::test]
+async fn test_generate_text_with_azure() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(DEPLOYMENT_PATH))
+        .and(query_param("api-version", "2024-10-21"))
+        .and(header("api-key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion("hello", "stop", false)))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(azure(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+
+    let requests = server.received_requests().await.expect("Expected requests");
+    assert!(requests[0].headers.get("authorization").is_none());
+}
+
+#[tokio::test]
+async fn test_generate_text_with_azure_ad_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(DEPLOYMENT_PATH))
+        .and(header("authorization", "Bearer ad-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion("hello", "stop", false)))
+        .mount(&server)
+        .await;
+
+    let settings = AzureProviderSettings::builder()
+        .endpoint(server.uri())
+        .deployment_name("gpt-4o-prod")
+        .ad_token("ad-token")
+        .build()
+        .expect("Failed to build AzureProviderSettings");
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(Azure::new(settings), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_content_filter_results() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(DEPLOYMENT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion("", "stop", true)))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(azure(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.finish_reason, FinishReason::ContentFilter);
+}
+
+#[tokio::test]
+async fn test_stream_text_with_azure() {
+    let chunks = [
+        json!({"id": "", "model": "", "choices": [],
+            "prompt_filter_results": [{"prompt_index": 0, "content_filter_results": {}}]}),
+        json!({"id": "chatcmpl-1", "model": "gpt-4o-2024-08-06",
+            "choices": [{"index": 0, "delta": {"content": "hel"}, "finish_reason": null,
+                "content_filter_results": {"hate": {"filtered": false, "severity": "safe"}}}]}),
+        json!({"id": "chatcmpl-1", "model": "gpt-4o-2024-08-06",
+            "choices": [{"index": 0, "delta": {}, "finish_reason": "content_filter",
+                "content_filter_results": {"violence": {"filtered": true, "severity": "high"}}}]}),
+    ];
+    let body: String = chunks
+        .iter()
+        .map(|chunk| format!("data: {chunk}\n\n"))
+        .chain(["data: [DONE]\n\n".to_string()])
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(DEPLOYMENT_PATH))
+        .and(query_param("api-version", "2024-10-21"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(azure(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks,
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::ContentFilter,
+            },
+        ]
+    );
+}
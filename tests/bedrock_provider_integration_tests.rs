@@ -0,0 +1,175 @@
+//! Integration tests for the Bedrock provider, run against a local mock
+//! server with fixed test credentials.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, StreamTextCallOptions,
+        generate_text, stream_text,
+    },
+    providers::bedrock::{AwsCredentials, Bedrock, BedrockProviderSettings},
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header_exists, method, path},
+};
+
+fn bedrock(server: &MockServer) -> Bedrock {
+    let settings = BedrockProviderSettings::builder()
+        .region("us-west-2")
+        .model_name("amazon.nova-lite-v1:0")
+        .base_url(server.uri())
+        .credentials(AwsCredentials::new(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+        ))
+        .build()
+        .expect("Failed to build BedrockProviderSettings");
+
+    Bedrock::new(settings)
+}
+
+/// Encodes an event of a `ConverseStream` response in the event stream
+/// binary format.
+fn encode_event(event_type: &str, payload: serde_json::Value) -> Vec<u8> {
+    let mut headers = Vec::new();
+    for (name, value) in [
+        (":event-type", event_type),
+        (":content-type", "application/json"),
+        (":message-type", "event"),
+    ] {
+        headers.push(name.len() as u8);
+        headers.extend_from_slice(name.as_bytes());
+        headers.push(7);
+        headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        headers.extend_from_slice(value.as_bytes());
+    }
+    let payload = payload.to_string().into_bytes();
+    let total_length = 12 + headers.len() + payload.len() + 4;
+
+    let mut message = Vec::new();
+    message.extend_from_slice(&(total_length as u32).to_be_bytes());
+    message.extend_from_slice(&(headers.len() as u32).to_be_bytes());
+    message.extend_from_slice(&crc32fast::hash(&message).to_be_bytes());
+    message.extend_from_slice(&headers);
+    message.extend_from_slice(&payload);
+    message.extend_from_slice(&crc32fast::hash(&message).to_be_bytes());
+    message
+}
+
+#[tokio::test]
+async fn test_generate_text_with_bedrock() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/model/amazon.nova-lite-v1%3A0/converse"))
+        .and(header_exists("x-amz-date"))
+        .and(body_partial_json(json!({
+            "messages": [{"role": "user", "content": [{"text": "Say hello"}]}],
+            "inferenceConfig": {"temperature": 0.5}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "output": {"message": {"role": "assistant", "content": [{"text": "hello"}]}},
+            "stopReason": "end_turn",
+            "usage": {"inputTokens": 12, "outputTokens": 2, "totalTokens": 14},
+            "metrics": {"latencyMs": 250}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .temperature(0.5)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(bedrock(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+
+    let requests = server.received_requests().await.unwrap();
+    let authorization = requests[0].headers["authorization"].to_str().unwrap();
+    assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+    assert!(authorization.contains("/us-west-2/bedrock/aws4_request"));
+}
+
+#[tokio::test]
+async fn test_stream_text_with_bedrock() {
+    let body: Vec<u8> = [
+        encode_event("messageStart", json!({"role": "assistant"})),
+        encode_event(
+            "contentBlockDelta",
+            json!({"contentBlockIndex": 0, "delta": {"text": "hel"}}),
+        ),
+        encode_event(
+            "contentBlockDelta",
+            json!({"contentBlockIndex": 0, "delta": {"text": "lo"}}),
+        ),
+        encode_event("contentBlockStop", json!({"contentBlockIndex": 0})),
+        encode_event("messageStop", json!({"stopReason": "end_turn"})),
+        encode_event(
+            "metadata",
+            json!({"usage": {"inputTokens": 12, "outputTokens": 2, "totalTokens": 14},
+                "metrics": {"latencyMs": 250}}),
+        ),
+    ]
+    .concat();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/model/amazon.nova-lite-v1%3A0/converse-stream"))
+        .and(header_exists("authorization"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(body, "application/vnd.amazon.eventstream"),
+        )
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(bedrock(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    assert!(matches!(chunks[3], LanguageModelStreamChunk::Usage(_)));
+}
+
+#[tokio::test]
+async fn test_bedrock_api_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "message": "The security token included in the request is invalid."
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let error = generate_text(bedrock(&server), options)
+        .await
+        .expect_err("Expected an API error");
+
+    assert!(error.to_string().contains("security token"));
+}
@@ -0,0 +1,190 @@
+//! Integration tests for the Cohere provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        EmbedManyCallOptions, FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk,
+        StreamTextCallOptions, embed_many, generate_text, stream_text,
+    },
+    providers::cohere::{Cohere, CohereEmbeddingSettings, CohereProviderSettings, EmbedInputType},
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path},
+};
+
+fn cohere(server: &MockServer) -> Cohere {
+    let settings = CohereProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(server.uri())
+        .build()
+        .expect("Failed to build CohereProviderSettings");
+
+    Cohere::new(settings)
+}
+
+#[tokio::test]
+async fn test_generate_text_with_cohere() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(body_partial_json(json!({
+            "model": "command-a-03-2025",
+            "messages": [{"role": "user", "content": "Say hello"}],
+            "k": 5
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "c14c80c3",
+            "finish_reason": "COMPLETE",
+            "message": {"role": "assistant", "content": [{"type": "text", "text": "hello"}]},
+            "usage": {"billed_units": {"input_tokens": 12, "output_tokens": 2},
+                "tokens": {"input_tokens": 12, "output_tokens": 2}}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .top_k(5)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(cohere(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_stream_text_with_cohere() {
+    let events = [
+        json!({"type": "message-start", "id": "c14c80c3", "delta": {"message": {"role": "assistant"}}}),
+        json!({"type": "content-start", "index": 0,
+            "delta": {"message": {"content": {"type": "text", "text": ""}}}}),
+        json!({"type": "content-delta", "index": 0, "delta": {"message": {"content": {"text": "hel"}}}}),
+        json!({"type": "content-delta", "index": 0, "delta": {"message": {"content": {"text": "lo"}}}}),
+        json!({"type": "content-end", "index": 0}),
+        json!({"type": "message-end", "delta": {"finish_reason": "COMPLETE",
+            "usage": {"tokens": {"input_tokens": 12, "output_tokens": 2}}}}),
+    ];
+    let body: String = events
+        .iter()
+        .map(|event| {
+            format!(
+                "event: {}\ndata: {event}\n\n",
+                event["type"].as_str().unwrap()
+            )
+        })
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(cohere(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    assert!(matches!(chunks[3], LanguageModelStreamChunk::Usage(_)));
+}
+
+#[tokio::test]
+async fn test_rerank_with_cohere() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rerank"))
+        .and(body_partial_json(json!({
+            "model": "rerank-v3.5",
+            "query": "capital of France",
+            "top_n": 2
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "07734bd2",
+            "results": [
+                {"index": 1, "relevance_score": 0.98},
+                {"index": 0, "relevance_score": 0.12}
+            ],
+            "meta": {"billed_units": {"search_units": 1}}
+        })))
+        .mount(&server)
+        .await;
+
+    let documents = vec![
+        "Berlin is the capital of Germany.".to_string(),
+        "Paris is the capital of France.".to_string(),
+        "Rome is the capital of Italy.".to_string(),
+    ];
+    let ranked = cohere(&server)
+        .rerank("capital of France", documents, Some(2))
+        .await
+        .expect("Failed to rerank documents");
+
+    assert_eq!(ranked.len(), 2);
+    assert_eq!(ranked[0].index, 1);
+    assert_eq!(ranked[0].document, "Paris is the capital of France.");
+}
+
+#[tokio::test]
+async fn test_embed_many_with_cohere() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/embed"))
+        .and(body_partial_json(json!({
+            "model": "embed-v4.0",
+            "texts": ["hello", "world"],
+            "input_type": "search_query",
+            "embedding_types": ["float"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "da6e531f",
+            "embeddings": {"float": [[0.1, 0.2], [0.3, 0.4]]},
+            "texts": ["hello", "world"],
+            "meta": {"billed_units": {"input_tokens": 2}}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let settings = CohereEmbeddingSettings::builder()
+        .input_type(EmbedInputType::SearchQuery)
+        .build()
+        .expect("Failed to build CohereEmbeddingSettings");
+    let model = cohere(&server).embedding_model(settings);
+
+    let options = EmbedManyCallOptions::builder()
+        .values(vec!["hello".to_string(), "world".to_string()])
+        .build()
+        .expect("Failed to build EmbedManyCallOptions");
+
+    let response = embed_many(model, options)
+        .await
+        .expect("Failed to embed values");
+
+    assert_eq!(response.embeddings, vec![vec![0.1, 0.2], vec![0.3, 0.4]]);
+    assert_eq!(response.usage.expect("Expected usage").tokens, 2);
+}
@@ -0,0 +1,183 @@
+//! Integration tests for the Google provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    Error,
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, StreamTextCallOptions,
+        generate_text, stream_text,
+    },
+    providers::google::{Google, GoogleProviderSettings, SafetySetting},
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path, query_param},
+};
+
+fn google(server: &MockServer) -> Google {
+    let settings = GoogleProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(server.uri())
+        .safety_settings(vec![SafetySetting::new(
+            "HARM_CATEGORY_HARASSMENT",
+            "BLOCK_ONLY_HIGH",
+        )])
+        .build()
+        .expect("Failed to build GoogleProviderSettings");
+
+    Google::new(settings)
+}
+
+#[tokio::test]
+async fn test_generate_text_with_google() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .and(header("x-goog-api-key", "test-key"))
+        .and(body_partial_json(json!({
+            "systemInstruction": {"parts": [{"text": "be brief"}]},
+            "contents": [{"role": "user", "parts": [{"text": "Say hello"}]}],
+            "safetySettings": [{"category": "HARM_CATEGORY_HARASSMENT", "threshold": "BLOCK_ONLY_HIGH"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{
+                "content": {"role": "model", "parts": [{"text": "hello"}]},
+                "finishReason": "STOP"
+            }],
+            "usageMetadata": {"promptTokenCount": 12, "candidatesTokenCount": 2, "totalTokenCount": 14},
+            "modelVersion": "gemini-2.5-flash"
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .system("be brief")
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(google(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_safety_blocks() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "candidates": [{"finishReason": "SAFETY"}]
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(google(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "");
+    assert_eq!(result.finish_reason, FinishReason::ContentFilter);
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_blocked_prompts() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "promptFeedback": {"blockReason": "PROHIBITED_CONTENT"}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(google(&server), options).await;
+
+    let Err(Error::ContentFilterError(details)) = result else {
+        panic!("expected a content filter error, got {result:?}");
+    };
+    assert_eq!(details.code.as_deref(), Some("PROHIBITED_CONTENT"));
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_api_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": {"code": 400, "message": "API key not valid", "status": "INVALID_ARGUMENT"}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(google(&server), options).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_stream_text_with_google() {
+    let responses = [
+        json!({"candidates": [{"content": {"role": "model", "parts": [{"text": "hel"}]}}]}),
+        json!({"candidates": [{"content": {"role": "model", "parts": [{"text": "lo"}]},
+            "finishReason": "STOP"}],
+            "usageMetadata": {"promptTokenCount": 12, "candidatesTokenCount": 2, "totalTokenCount": 14}}),
+    ];
+    let body: String = responses
+        .iter()
+        .map(|response| format!("data: {response}\r\n\r\n"))
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:streamGenerateContent"))
+        .and(query_param("alt", "sse"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(google(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    let LanguageModelStreamChunk::Usage(usage) = &chunks[3] else {
+        panic!("Expected usage, got {:?}", chunks[3]);
+    };
+    assert_eq!(usage.total_tokens, 14);
+}
@@ -0,0 +1,219 @@
+//! Integration tests for the Mistral provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateObjectCallOptions, GenerateTextCallOptions, JsonSchema,
+        LanguageModelStreamChunk, StreamTextCallOptions, Tool, generate_object, generate_text,
+        stream_text,
+    },
+    providers::mistral::{Mistral, MistralProviderSettings},
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, body_string_contains, header, method, path},
+};
+
+fn mistral(server: &MockServer) -> Mistral {
+    let settings = MistralProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(server.uri())
+        .safe_prompt(true)
+        .build()
+        .expect("Failed to build MistralProviderSettings");
+
+    Mistral::new(settings)
+}
+
+fn completion(message: Value, finish_reason: &str) -> Value {
+    json!({
+        "id": "cmpl-1",
+        "object": "chat.completion",
+        "created": 1,
+        "model": "mistral-large-latest",
+        "choices": [{"index": 0, "message": message, "finish_reason": finish_reason}],
+        "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}
+    })
+}
+
+#[tokio::test]
+async fn test_generate_text_with_mistral() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(body_partial_json(json!({
+            "model": "mistral-large-latest",
+            "messages": [{"role": "user", "content": "Say hello"}],
+            "random_seed": 7,
+            "safe_prompt": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion(
+            json!({"role": "assistant", "content": "hello"}),
+            "stop",
+        )))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .seed(7)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(mistral(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[derive(Debug)]
+struct Weather;
+
+#[async_trait]
+impl Tool for Weather {
+    fn name(&self) -> &str {
+        "get_weather"
+    }
+
+    fn description(&self) -> &str {
+        "Gets the current temperature in a city."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({"type": "object", "properties": {"city": {"type": "string"}}})
+    }
+
+    async fn execute(&self, _arguments: Value) -> ai_sdk_rs::Result<Value> {
+        Ok(json!(21))
+    }
+}
+
+#[tokio::test]
+async fn test_generate_text_with_mistral_tools() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_string_contains(
+            r#""tool_call_id":"D681PevKs","name":"get_weather""#,
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion(
+            json!({"role": "assistant", "content": "It is 21 degrees."}),
+            "stop",
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion(
+            json!({"role": "assistant", "content": "", "tool_calls": [{
+                "id": "D681PevKs",
+                "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}
+            }]}),
+            "tool_calls",
+        )))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("What is the weather in Paris?")
+        .tool(Weather)
+        .max_steps(2)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(mistral(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.steps.len(), 2);
+    assert_eq!(
+        result.steps[0].tool_calls[0].arguments,
+        json!({"city": "Paris"})
+    );
+    assert_eq!(result.text, "It is 21 degrees.");
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct City {
+    name: String,
+}
+
+#[tokio::test]
+async fn test_generate_object_with_mistral() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({
+            "response_format": {"type": "json_schema", "json_schema": {"name": "City"}}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion(
+            json!({"role": "assistant", "content": "{\"name\": \"Paris\"}"}),
+            "stop",
+        )))
+        .mount(&server)
+        .await;
+
+    let options = GenerateObjectCallOptions::builder()
+        .prompt("Name the capital of France.")
+        .build()
+        .expect("Failed to build GenerateObjectCallOptions");
+
+    let result = generate_object::<City>(mistral(&server), options)
+        .await
+        .expect("Failed to generate object");
+
+    assert_eq!(result.object.name, "Paris");
+}
+
+#[tokio::test]
+async fn test_stream_text_with_mistral() {
+    let chunks = [
+        json!({"id": "cmpl-1", "model": "mistral-large-latest",
+            "choices": [{"index": 0, "delta": {"role": "assistant", "content": "hel"}, "finish_reason": null}]}),
+        json!({"id": "cmpl-1", "model": "mistral-large-latest",
+            "choices": [{"index": 0, "delta": {"content": "lo"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}}),
+    ];
+    let body: String = chunks
+        .iter()
+        .map(|chunk| format!("data: {chunk}\n\n"))
+        .chain(["data: [DONE]\n\n".to_string()])
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(mistral(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    assert!(matches!(chunks[3], LanguageModelStreamChunk::Usage(_)));
+}
@@ -0,0 +1,240 @@
+//! Integration tests for the Ollama provider, run against a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, StreamTextCallOptions,
+        Tool, generate_text, stream_text,
+    },
+    providers::ollama::{Ollama, OllamaProviderSettings},
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path},
+};
+
+fn ollama(server: &MockServer) -> Ollama {
+    let settings = OllamaProviderSettings::builder()
+        .base_url(server.uri())
+        .keep_alive("5m")
+        .build()
+        .expect("Failed to build OllamaProviderSettings");
+
+    Ollama::new(settings)
+}
+
+#[tokio::test]
+async fn test_generate_text_with_ollama() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .and(body_partial_json(json!({
+            "model": "llama3.2",
+            "messages": [
+                {"role": "system", "content": "be brief"},
+                {"role": "user", "content": "Say hello"}
+            ],
+            "stream": false,
+            "keep_alive": "5m"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "model": "llama3.2",
+            "created_at": "2025-01-01T00:00:00Z",
+            "message": {"role": "assistant", "content": "hello"},
+            "done": true,
+            "done_reason": "stop",
+            "prompt_eval_count": 12,
+            "eval_count": 2
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .system("be brief")
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(ollama(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[derive(Debug)]
+struct Weather;
+
+#[async_trait]
+impl Tool for Weather {
+    fn name(&self) -> &str {
+        "get_weather"
+    }
+
+    fn description(&self) -> &str {
+        "Get the current weather for a city."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "city": { "type": "string" } },
+            "required": ["city"]
+        })
+    }
+
+    async fn execute(&self, arguments: Value) -> ai_sdk_rs::Result<Value> {
+        Ok(json!({ "city": arguments["city"], "temperature": 21 }))
+    }
+}
+
+#[tokio::test]
+async fn test_generate_text_numbers_tool_calls_across_steps() {
+    let server = MockServer::start().await;
+    for city in ["Paris", "Rome"] {
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "model": "llama3.2",
+                "created_at": "2025-01-01T00:00:00Z",
+                "message": {"role": "assistant", "content": "", "tool_calls": [
+                    {"function": {"name": "get_weather", "arguments": {"city": city}}}
+                ]},
+                "done": true,
+                "done_reason": "stop"
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "model": "llama3.2",
+            "created_at": "2025-01-01T00:00:00Z",
+            "message": {"role": "assistant", "content": "21 degrees in both."},
+            "done": true,
+            "done_reason": "stop"
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Weather in Paris and Rome?")
+        .tool(Weather)
+        .max_steps(3)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(ollama(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "21 degrees in both.");
+    let ids: Vec<_> = result
+        .steps
+        .iter()
+        .flat_map(|step| &step.tool_calls)
+        .map(|call| call.id.as_str())
+        .collect();
+    assert_eq!(ids, ["call_0", "call_1"]);
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_missing_models() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(
+                json!({"error": "model \"llama3.2\" not found, try pulling it first"}),
+            ),
+        )
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(ollama(&server), options).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_stream_text_with_ollama() {
+    let lines = [
+        json!({"model": "llama3.2", "message": {"role": "assistant", "content": "hel"}, "done": false}),
+        json!({"model": "llama3.2", "message": {"role": "assistant", "content": "lo"}, "done": false}),
+        json!({"model": "llama3.2", "message": {"role": "assistant", "content": ""}, "done": true,
+            "done_reason": "stop", "prompt_eval_count": 12, "eval_count": 2}),
+    ];
+    let body: String = lines.iter().map(|line| format!("{line}\n")).collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/x-ndjson"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(ollama(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks[..3],
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+    let LanguageModelStreamChunk::Usage(usage) = &chunks[3] else {
+        panic!("Expected usage, got {:?}", chunks[3]);
+    };
+    assert_eq!(usage.total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_list_models() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "models": [{
+                "name": "llama3.2:latest",
+                "model": "llama3.2:latest",
+                "modified_at": "2025-01-01T00:00:00Z",
+                "size": 2019393189_u64,
+                "digest": "a80c4f17acd5",
+                "details": {"format": "gguf", "family": "llama", "parameter_size": "3.2B",
+                    "quantization_level": "Q4_K_M"}
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let models = ollama(&server)
+        .list_models()
+        .await
+        .expect("Failed to list models");
+
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0].name, "llama3.2:latest");
+}
@@ -1,8 +1,29 @@
-//! Integration tests for the OpenAI provider.
+//! Integration tests for the OpenAI provider. The tests against the live API
+//! are skipped unless `OPENAI_API_KEY` is set, the others run against a local
+//! mock server.
 
 use ai_sdk_rs::{
-    core::{GenerateTextCallOptions, generate_text},
-    providers::openai::{OpenAI, OpenAIProviderSettings},
+    core::{
+        AudioFormat, ContentPart, EmbedManyCallOptions, FinishReason, GenerateImageCallOptions,
+        GenerateObjectCallOptions, GenerateSpeechCallOptions, GenerateTextCallOptions, ImageData,
+        ImageOutput, JsonSchema, LanguageModelStreamChunk, Message, RetryPolicy,
+        StreamTextCallOptions, Tool, TranscribeCallOptions, embed_many, generate_image,
+        generate_object, generate_speech, generate_text, stream_speech, stream_text, transcribe,
+    },
+    error::Error,
+    providers::openai::{
+        OpenAI, OpenAIEmbeddingSettings, OpenAIImageSettings, OpenAIProviderSettings,
+        OpenAISpeechSettings, OpenAITranscriptionSettings,
+    },
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::time::Duration;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, body_string_contains, header, method, path},
 };
 
 #[tokio::test]
@@ -35,3 +56,658 @@ async fn test_generate_text_with_openai() {
     let text = result.as_ref().expect("Failed to get result").text.trim();
     assert!(text.contains("hello"));
 }
+
+#[tokio::test]
+async fn test_stream_text_with_openai() {
+    // This test requires a valid OpenAI API key to be set in the environment.
+    if std::env::var("OPENAI_API_KEY").is_err() {
+        println!("Skipping test: OPENAI_API_KEY not set");
+        return;
+    }
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key(std::env::var("OPENAI_API_KEY").unwrap())
+        .model_name("gpt-4o".to_string())
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+
+    let openai = OpenAI::new(settings);
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Respond with exactly the word 'hello' in all lowercase.")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(openai, options)
+        .await
+        .expect("Failed to start stream");
+    let text: String = response.text_stream().collect::<Vec<_>>().await.concat();
+
+    assert!(text.contains("hello"));
+}
+
+#[derive(Debug)]
+struct Weather;
+
+#[async_trait]
+impl Tool for Weather {
+    fn name(&self) -> &str {
+        "get_weather"
+    }
+
+    fn description(&self) -> &str {
+        "Get the current weather for a city."
+    }
+
+    fn parameters(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "city": { "type": "string" } },
+            "required": ["city"]
+        })
+    }
+
+    async fn execute(&self, arguments: Value) -> ai_sdk_rs::Result<Value> {
+        Ok(json!({ "city": arguments["city"], "temperature": 21 }))
+    }
+}
+
+#[tokio::test]
+async fn test_generate_text_with_tools_with_openai() {
+    // This test requires a valid OpenAI API key to be set in the environment.
+    if std::env::var("OPENAI_API_KEY").is_err() {
+        println!("Skipping test: OPENAI_API_KEY not set");
+        return;
+    }
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key(std::env::var("OPENAI_API_KEY").unwrap())
+        .model_name("gpt-4o".to_string())
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+
+    let openai = OpenAI::new(settings);
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("What is the weather in Paris?")
+        .tool(Weather)
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(openai, options)
+        .await
+        .expect("Failed to generate text");
+
+    let call = result.tool_calls.first().expect("Expected a tool call");
+    assert_eq!(call.name, "get_weather");
+    assert_eq!(call.arguments["city"], "Paris");
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct City {
+    name: String,
+    country: String,
+}
+
+#[tokio::test]
+async fn test_generate_object_with_openai() {
+    // This test requires a valid OpenAI API key to be set in the environment.
+    if std::env::var("OPENAI_API_KEY").is_err() {
+        println!("Skipping test: OPENAI_API_KEY not set");
+        return;
+    }
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key(std::env::var("OPENAI_API_KEY").unwrap())
+        .model_name("gpt-4o".to_string())
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+
+    let openai = OpenAI::new(settings);
+
+    let options = GenerateObjectCallOptions::builder()
+        .prompt("Which city is the capital of France?")
+        .build()
+        .expect("Failed to build GenerateObjectCallOptions");
+
+    let result = generate_object::<City>(openai, options)
+        .await
+        .expect("Failed to generate object");
+
+    assert_eq!(result.object.name, "Paris");
+    assert_eq!(result.object.country, "France");
+}
+
+fn compatible(server: &MockServer) -> OpenAI {
+    let settings = OpenAIProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(format!("{}/v1", server.uri()))
+        .header("X-Title", "ai-sdk-rs")
+        .model_name("llama-3.1-8b")
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+
+    OpenAI::new(settings)
+}
+
+#[tokio::test]
+async fn test_generate_text_with_compatible_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(header("x-title", "ai-sdk-rs"))
+        .and(body_partial_json(json!({
+            "model": "llama-3.1-8b",
+            "messages": [{"role": "user", "content": "Say hello"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "llama-3.1-8b",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(compatible(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_generate_text_with_image_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(body_partial_json(json!({
+            "messages": [{"role": "user", "content": [
+                {"type": "text", "text": "What is the total?"},
+                {"type": "image_url", "image_url": {"url": "data:image/png;base64,iVBORw0KGgo="}}
+            ]}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "The total is $42."},
+                "finish_reason": "stop"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .messages(vec![Message::user_with_parts(vec![
+            ContentPart::text("What is the total?"),
+            ContentPart::image(b"\x89PNG\r\n\x1a\n".to_vec(), "image/png"),
+        ])])
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(compatible(&server), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "The total is $42.");
+}
+
+#[tokio::test]
+async fn test_generate_text_reports_rate_limit_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("retry-after", "7")
+                .insert_header("x-request-id", "req_123")
+                .set_body_json(json!({
+                    "error": {
+                        "message": "Rate limit reached for gpt-4o",
+                        "type": "requests",
+                        "code": "rate_limit_exceeded"
+                    }
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let error = generate_text(compatible(&server), options)
+        .await
+        .expect_err("Expected a rate limit error");
+
+    assert!(error.is_retryable());
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(7)));
+    let Error::RateLimitError { details, .. } = error else {
+        panic!("Expected a rate limit error, got {error:?}");
+    };
+    assert_eq!(details.status, Some(429));
+    assert_eq!(details.code.as_deref(), Some("rate_limit_exceeded"));
+    assert_eq!(details.request_id.as_deref(), Some("req_123"));
+}
+
+#[tokio::test]
+async fn test_generate_text_retries_server_errors_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "error": {"message": "The server is overloaded", "type": "server_error"}
+        })))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "finish_reason": "stop"
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(format!("{}/v1", server.uri()))
+        .retry_policy(
+            RetryPolicy::builder()
+                .max_attempts(3)
+                .base_delay(Duration::from_millis(10))
+                .build()
+                .expect("Failed to build RetryPolicy"),
+        )
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(OpenAI::new(settings), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+}
+
+#[tokio::test]
+async fn test_generate_text_without_api_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "local",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "finish_reason": "stop"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key("")
+        .base_url(format!("{}/v1", server.uri()))
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    generate_text(OpenAI::new(settings), options)
+        .await
+        .expect("Failed to generate text");
+
+    let requests = server.received_requests().await.expect("Expected requests");
+    assert!(requests[0].headers.get("authorization").is_none());
+}
+
+#[tokio::test]
+async fn test_stream_text_with_compatible_endpoint() {
+    let chunks = [
+        json!({"id": "chatcmpl-1", "model": "llama-3.1-8b",
+            "choices": [{"index": 0, "delta": {"role": "assistant", "content": "hel"}}]}),
+        json!({"id": "chatcmpl-1", "model": "llama-3.1-8b",
+            "choices": [{"index": 0, "delta": {"content": "lo"}, "finish_reason": "stop"}]}),
+    ];
+    let body: String = chunks
+        .iter()
+        .map(|chunk| format!("data: {chunk}\n\n"))
+        .chain(["data: [DONE]\n\n".to_string()])
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(body_partial_json(json!({"stream": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(compatible(&server), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks,
+        [
+            LanguageModelStreamChunk::TextDelta("hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_embed_many_with_openai() {
+    let server = MockServer::start().await;
+    for (input, data) in [
+        (
+            json!(["sunny day", "rainy day"]),
+            json!([
+                {"object": "embedding", "index": 1, "embedding": [0.3, 0.4]},
+                {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}
+            ]),
+        ),
+        (
+            json!(["snowy day"]),
+            json!([{"object": "embedding", "index": 0, "embedding": [0.5, 0.6]}]),
+        ),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .and(body_partial_json(json!({
+                "model": "text-embedding-3-small",
+                "input": input,
+                "dimensions": 2,
+                "encoding_format": "float"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "model": "text-embedding-3-small",
+                "data": data,
+                "usage": {"prompt_tokens": 4, "total_tokens": 4}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let settings = OpenAIEmbeddingSettings::builder()
+        .dimensions(2)
+        .max_embeddings_per_call(2)
+        .build()
+        .expect("Failed to build OpenAIEmbeddingSettings");
+    let model = compatible(&server).embedding_model(settings);
+
+    let options = EmbedManyCallOptions::builder()
+        .values(vec![
+            "sunny day".to_string(),
+            "rainy day".to_string(),
+            "snowy day".to_string(),
+        ])
+        .build()
+        .expect("Failed to build EmbedManyCallOptions");
+
+    let response = embed_many(model, options)
+        .await
+        .expect("Failed to embed values");
+
+    assert_eq!(
+        response.embeddings,
+        vec![vec![0.1, 0.2], vec![0.3, 0.4], vec![0.5, 0.6]]
+    );
+    assert_eq!(response.usage.expect("Expected usage").tokens, 8);
+}
+
+#[tokio::test]
+async fn test_generate_image_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/images/generations"))
+        .and(body_partial_json(json!({
+            "model": "dall-e-3",
+            "prompt": "a lighthouse at dusk",
+            "n": 1,
+            "size": "1024x1792",
+            "quality": "hd",
+            "style": "natural",
+            "response_format": "b64_json"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "created": 1727000000,
+            "data": [{
+                "b64_json": "iVBORw0KGgo=",
+                "revised_prompt": "A lighthouse on a rocky coast at dusk."
+            }]
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let model = compatible(&server).image_model(
+        OpenAIImageSettings::builder()
+            .build()
+            .expect("Failed to build OpenAIImageSettings"),
+    );
+
+    let options = GenerateImageCallOptions::builder()
+        .prompt("a lighthouse at dusk")
+        .n(2)
+        .size("1024x1792")
+        .quality("hd")
+        .style("natural")
+        .output(ImageOutput::Bytes)
+        .build()
+        .expect("Failed to build GenerateImageCallOptions");
+
+    let response = generate_image(model, options)
+        .await
+        .expect("Failed to generate images");
+
+    assert_eq!(response.images.len(), 2);
+    assert_eq!(
+        response.images[0].data,
+        ImageData::Bytes(b"\x89PNG\r\n\x1a\n".to_vec())
+    );
+    assert_eq!(
+        response.images[0].revised_prompt.as_deref(),
+        Some("A lighthouse on a rocky coast at dusk.")
+    );
+}
+
+#[tokio::test]
+async fn test_generate_and_stream_speech_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/speech"))
+        .and(body_partial_json(json!({
+            "model": "gpt-4o-mini-tts",
+            "input": "Hello there!",
+            "voice": "coral",
+            "response_format": "wav",
+            "speed": 1.25
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(b"RIFF0000WAVE".to_vec(), "audio/wav"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let model = compatible(&server).speech_model(
+        OpenAISpeechSettings::builder()
+            .voice("coral")
+            .build()
+            .expect("Failed to build OpenAISpeechSettings"),
+    );
+    let options = GenerateSpeechCallOptions::builder()
+        .text("Hello there!")
+        .format(AudioFormat::Wav)
+        .speed(1.25)
+        .build()
+        .expect("Failed to build GenerateSpeechCallOptions");
+
+    let response = generate_speech(model.clone(), options.clone())
+        .await
+        .expect("Failed to generate speech");
+    assert_eq!(response.audio, b"RIFF0000WAVE");
+    assert_eq!(response.media_type, "audio/wav");
+
+    let response = stream_speech(model, options)
+        .await
+        .expect("Failed to start speech stream");
+    let chunks: Vec<Vec<u8>> = response
+        .stream
+        .map(|chunk| chunk.expect("Failed to read audio"))
+        .collect()
+        .await;
+    assert_eq!(chunks.concat(), b"RIFF0000WAVE");
+}
+
+#[tokio::test]
+async fn test_transcribe_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/transcriptions"))
+        .and(body_string_contains("filename=\"audio.wav\""))
+        .and(body_string_contains("whisper-1"))
+        .and(body_string_contains("verbose_json"))
+        .and(body_string_contains("timestamp_granularities[]"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "task": "transcribe",
+            "language": "english",
+            "duration": 2.5,
+            "text": "Hello there! How are you?",
+            "segments": [
+                {"id": 0, "seek": 0, "start": 0.0, "end": 1.2, "text": " Hello there!"},
+                {"id": 1, "seek": 0, "start": 1.2, "end": 2.5, "text": " How are you?"}
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let file = tempfile::Builder::new()
+        .suffix(".wav")
+        .tempfile()
+        .expect("Failed to create audio file");
+    std::fs::write(file.path(), b"RIFF0000WAVEfmt ").expect("Failed to write audio file");
+
+    let model = compatible(&server).transcription_model(
+        OpenAITranscriptionSettings::builder()
+            .build()
+            .expect("Failed to build OpenAITranscriptionSettings"),
+    );
+    let options = TranscribeCallOptions::builder()
+        .audio(file.path())
+        .build()
+        .expect("Failed to build TranscribeCallOptions");
+
+    let response = transcribe(model, options)
+        .await
+        .expect("Failed to transcribe audio");
+
+    assert_eq!(response.text, "Hello there! How are you?");
+    assert_eq!(response.language.as_deref(), Some("english"));
+    assert_eq!(response.duration, Some(2.5));
+    assert_eq!(response.segments.len(), 2);
+    assert_eq!(response.segments[1].start, 1.2);
+    assert_eq!(response.segments[1].text, " How are you?");
+}
+
+#[tokio::test]
+async fn test_transcribe_retries_server_errors_with_openai() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/transcriptions"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "error": {"message": "The server is overloaded", "type": "server_error"}
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/transcriptions"))
+        .and(body_string_contains("RIFF0000WAVE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "text": "Hello there!"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let settings = OpenAIProviderSettings::builder()
+        .api_key("test-key")
+        .base_url(format!("{}/v1", server.uri()))
+        .retry_policy(
+            RetryPolicy::builder()
+                .base_delay(Duration::from_millis(10))
+                .build()
+                .expect("Failed to build RetryPolicy"),
+        )
+        .build()
+        .expect("Failed to build OpenAIProviderSettings");
+    let model = OpenAI::new(settings).transcription_model(
+        OpenAITranscriptionSettings::builder()
+            .build()
+            .expect("Failed to build OpenAITranscriptionSettings"),
+    );
+    let options = TranscribeCallOptions::builder()
+        .audio(b"RIFF0000WAVEfmt ".to_vec())
+        .build()
+        .expect("Failed to build TranscribeCallOptions");
+
+    let response = transcribe(model, options)
+        .await
+        .expect("Failed to transcribe audio");
+
+    assert_eq!(response.text, "Hello there!");
+}
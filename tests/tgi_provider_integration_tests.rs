@@ -0,0 +1,170 @@
+//! Integration tests for the Text Generation Inference provider, run against
+//! a local mock server.
+
+use ai_sdk_rs::{
+    core::{
+        FinishReason, GenerateTextCallOptions, LanguageModelStreamChunk, StreamTextCallOptions,
+        generate_text, stream_text,
+    },
+    providers::tgi::{Grammar, Tgi, TgiApi, TgiProviderSettings},
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, header, method, path},
+};
+
+#[tokio::test]
+async fn test_generate_text_with_tgi_messages_api() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer hf_test"))
+        .and(body_partial_json(json!({
+            "messages": [{"role": "user", "content": "Say hello"}],
+            "response_format": {"type": "regex", "value": "hello|hi"},
+            "stream": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "object": "chat.completion",
+            "id": "",
+            "created": 1727000000,
+            "model": "meta-llama/Llama-3.1-8B-Instruct",
+            "system_fingerprint": "2.3.0-native",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "logprobs": null,
+                "finish_reason": "eos_token"
+            }],
+            "usage": {"prompt_tokens": 12, "completion_tokens": 2, "total_tokens": 14}
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = TgiProviderSettings::builder()
+        .api_key("hf_test")
+        .base_url(server.uri())
+        .grammar(Grammar::Regex("hello|hi".to_string()))
+        .build()
+        .expect("Failed to build TgiProviderSettings");
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(Tgi::new(settings), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "hello");
+    assert_eq!(result.finish_reason, FinishReason::Stop);
+    assert_eq!(result.usage.expect("Expected usage").total_tokens, 14);
+}
+
+#[tokio::test]
+async fn test_generate_text_with_tgi_generate_api() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/generate"))
+        .and(body_partial_json(json!({
+            "inputs": "Write a haiku.",
+            "parameters": {
+                "max_new_tokens": 20,
+                "repetition_penalty": 1.2,
+                "details": true,
+                "return_full_text": false
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "generated_text": "Autumn moonlight",
+            "details": {
+                "finish_reason": "length",
+                "generated_tokens": 20,
+                "seed": null,
+                "prefill": [],
+                "tokens": []
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = TgiProviderSettings::builder()
+        .api_key("")
+        .base_url(server.uri())
+        .api(TgiApi::Generate)
+        .max_tokens(20)
+        .repetition_penalty(1.2)
+        .details(true)
+        .build()
+        .expect("Failed to build TgiProviderSettings");
+
+    let options = GenerateTextCallOptions::builder()
+        .prompt("Write a haiku.")
+        .build()
+        .expect("Failed to build GenerateTextCallOptions");
+
+    let result = generate_text(Tgi::new(settings), options)
+        .await
+        .expect("Failed to generate text");
+
+    assert_eq!(result.text, "Autumn moonlight");
+    assert_eq!(result.finish_reason, FinishReason::Length);
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].headers.contains_key("authorization"));
+}
+
+#[tokio::test]
+async fn test_stream_text_with_tgi_generate_api() {
+    let events = [
+        json!({"index": 1, "token": {"id": 9906, "text": "Hel", "logprob": -0.1, "special": false},
+            "generated_text": null, "details": null}),
+        json!({"index": 2, "token": {"id": 385, "text": "lo", "logprob": -0.2, "special": false},
+            "generated_text": null, "details": null}),
+        json!({"index": 3, "token": {"id": 2, "text": "</s>", "logprob": -0.3, "special": true},
+            "generated_text": "Hello",
+            "details": {"finish_reason": "eos_token", "generated_tokens": 3, "seed": null}}),
+    ];
+    let body: String = events
+        .iter()
+        .map(|event| format!("data:{event}\n\n"))
+        .collect();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/generate_stream"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let settings = TgiProviderSettings::builder()
+        .base_url(server.uri())
+        .api(TgiApi::Generate)
+        .details(true)
+        .build()
+        .expect("Failed to build TgiProviderSettings");
+
+    let options = StreamTextCallOptions::builder()
+        .prompt("Say hello")
+        .build()
+        .expect("Failed to build StreamTextCallOptions");
+
+    let response = stream_text(Tgi::new(settings), options)
+        .await
+        .expect("Failed to start stream");
+    let chunks: Vec<_> = response.stream.collect().await;
+
+    assert_eq!(
+        chunks,
+        [
+            LanguageModelStreamChunk::TextDelta("Hel".to_string()),
+            LanguageModelStreamChunk::TextDelta("lo".to_string()),
+            LanguageModelStreamChunk::Finish {
+                finish_reason: FinishReason::Stop,
+            },
+        ]
+    );
+}